@@ -76,7 +76,7 @@ async fn main() {
         .await;
 
     match llm_extraction_result {
-        Ok(data) => println!("LLM Extraction Result:\n{:#?}", data.extract.unwrap()),
+        Ok(data) => println!("LLM Extraction Result:\n{:#?}", data.json.unwrap()),
         Err(e) => eprintln!("LLM Extraction failed: {}", e),
     }
 
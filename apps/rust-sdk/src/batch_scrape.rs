@@ -92,7 +92,9 @@ impl FirecrawlApp {
             .json(&params)
             .send()
             .await
-            .map_err(|e| FirecrawlError::HttpError("Initiating batch scrape job".to_string(), e))?;
+            .map_err(|e| {
+                FirecrawlError::from_reqwest("Initiating batch scrape job".to_string(), e)
+            })?;
 
         self.handle_response(response, "initiate batch scrape job")
             .await
@@ -129,7 +131,7 @@ impl FirecrawlApp {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(
+                FirecrawlError::from_reqwest(
                     format!("Checking status of batch scrape {}", id.as_ref()),
                     e,
                 )
@@ -165,7 +167,7 @@ impl FirecrawlApp {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(
+                FirecrawlError::from_reqwest(
                     format!("Paginating batch scrape using URL {:?}", next.as_ref()),
                     e,
                 )
@@ -195,7 +197,7 @@ impl FirecrawlApp {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(
+                FirecrawlError::from_reqwest(
                     format!("Checking errors for batch scrape {}", id.as_ref()),
                     e,
                 )
@@ -174,6 +174,9 @@ pub struct CrawlOptions {
     /// Allows the crawler to follow links to external URLs. (default: `false`)
     pub allow_external_links: Option<bool>,
 
+    /// Allows the crawler to follow links to subdomains of the base URL. (default: `false`)
+    pub allow_subdomains: Option<bool>,
+
     /// URL to send Webhook crawl events to.
     pub webhook: Option<WebhookOptions>,
 
@@ -188,6 +191,22 @@ pub struct CrawlOptions {
     pub poll_interval: Option<u64>,
 }
 
+impl CrawlOptions {
+    fn validate(&self) -> Result<(), FirecrawlError> {
+        if self.max_depth == Some(0) {
+            return Err(FirecrawlError::Missuse(
+                "max_depth must be greater than 0".to_string(),
+            ));
+        }
+        if self.limit == Some(0) {
+            return Err(FirecrawlError::Missuse(
+                "limit must be greater than 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 struct CrawlRequestBody {
@@ -277,6 +296,8 @@ pub struct CrawlAsyncResponse {
 
 impl FirecrawlApp {
     /// Initiates a crawl job for a URL using the Firecrawl API.
+    ///
+    /// Returns `FirecrawlError::Missuse` without sending the request if `CrawlOptions.max_depth` or `CrawlOptions.limit` is zero.
     pub async fn crawl_url_async(
         &self,
         url: impl AsRef<str>,
@@ -286,6 +307,7 @@ impl FirecrawlApp {
             url: url.as_ref().to_string(),
             options: options.unwrap_or_default(),
         };
+        body.options.validate()?;
 
         let headers = self.prepare_headers(body.options.idempotency_key.as_ref());
 
@@ -296,7 +318,7 @@ impl FirecrawlApp {
             .json(&body)
             .send()
             .await
-            .map_err(|e| FirecrawlError::HttpError(format!("Crawling {:?}", url.as_ref()), e))?;
+            .map_err(|e| FirecrawlError::from_reqwest(format!("Crawling {:?}", url.as_ref()), e))?;
 
         self.handle_response::<CrawlAsyncResponse>(response, "start crawl job")
             .await
@@ -329,7 +351,7 @@ impl FirecrawlApp {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(
+                FirecrawlError::from_reqwest(
                     format!("Paginating crawl using URL {:?}", next.as_ref()),
                     e,
                 )
@@ -359,7 +381,7 @@ impl FirecrawlApp {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Checking status of crawl {}", id.as_ref()), e)
+                FirecrawlError::from_reqwest(format!("Checking status of crawl {}", id.as_ref()), e)
             })?;
 
         let mut status: CrawlStatus = self
@@ -431,7 +453,7 @@ impl FirecrawlApp {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Cancelling crawl {}", id.as_ref()), e)
+                FirecrawlError::from_reqwest(format!("Cancelling crawl {}", id.as_ref()), e)
             })?;
 
         self.handle_response(response, "crawl_cancel").await
@@ -458,7 +480,10 @@ impl FirecrawlApp {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Checking errors for crawl {}", id.as_ref()), e)
+                FirecrawlError::from_reqwest(
+                    format!("Checking errors for crawl {}", id.as_ref()),
+                    e,
+                )
             })?;
 
         self.handle_response(response, "crawl_check").await
@@ -539,6 +564,99 @@ mod tests {
         mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_cancel_crawl_error_status_without_success() {
+        let mut server = mockito::Server::new_async().await;
+
+        // Cancel responses skip the success check, so this used to parse as Ok
+        let mock = server
+            .mock("DELETE", "/v1/crawl/crawl-123")
+            .with_status(500)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "failed",
+                    "message": "Internal server error"
+                })
+                .to_string(),
+            )
+            .create();
+
+        let app = FirecrawlApp::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let result = app.cancel_crawl("crawl-123").await;
+
+        assert!(matches!(
+            result,
+            Err(FirecrawlError::APIError(ref action, ref error))
+                if action == "crawl_cancel" && error.error == "Internal server error"
+        ));
+        mock.assert();
+    }
+
+    #[test]
+    fn test_link_scope_serialization() {
+        let body = serde_json::to_value(CrawlRequestBody {
+            url: "https://example.com".to_string(),
+            options: CrawlOptions {
+                allow_external_links: Some(false),
+                allow_subdomains: Some(true),
+                ..Default::default()
+            },
+        })
+        .unwrap();
+
+        assert_eq!(
+            body,
+            json!({
+                "url": "https://example.com",
+                "allowExternalLinks": false,
+                "allowSubdomains": true
+            })
+        );
+
+        let body = serde_json::to_value(CrawlOptions::default()).unwrap();
+        assert!(body.get("allowExternalLinks").is_none());
+        assert!(body.get("allowSubdomains").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_crawl_url_async_rejects_zero_max_depth() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server.mock("POST", "/v1/crawl").expect(0).create();
+
+        let app = FirecrawlApp::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = CrawlOptions {
+            max_depth: Some(0),
+            ..Default::default()
+        };
+        let result = app
+            .crawl_url_async("https://example.com", Some(options))
+            .await;
+
+        assert!(matches!(result, Err(FirecrawlError::Missuse(_))));
+        mock.assert();
+    }
+
+    #[test]
+    fn test_crawl_options_validate() {
+        let options = CrawlOptions {
+            limit: Some(0),
+            ..Default::default()
+        };
+        assert!(matches!(
+            options.validate(),
+            Err(FirecrawlError::Missuse(ref message)) if message.contains("limit")
+        ));
+
+        let options = CrawlOptions {
+            max_depth: Some(2),
+            limit: Some(10),
+            ..Default::default()
+        };
+        assert!(options.validate().is_ok());
+        assert!(CrawlOptions::default().validate().is_ok());
+    }
+
     #[tokio::test]
     #[ignore = "Makes real network request"]
     async fn test_real_check_crawl_errors() {
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -47,6 +49,10 @@ pub struct DocumentMetadata {
     pub dc_date: Option<String>,
     pub dc_date_created: Option<String>,
     pub dcterms_created: Option<String>,
+
+    /// Any metadata keys not covered by the fields above, such as custom `<meta>` tags.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 #[serde_with::skip_serializing_none]
@@ -76,6 +82,10 @@ pub struct Document {
     /// If `ScrapeOptions.extract.schema` is `Some`, this `Value` is guaranteed to match the provided schema.
     pub extract: Option<Value>,
 
+    /// The structured data extracted from the page, present if `ScrapeFormats::Json` is present in `ScrapeOptions.formats`.
+    /// If `ScrapeOptions.json_options.schema` is `Some`, this `Value` is guaranteed to match the provided schema.
+    pub json: Option<Value>,
+
     /// The metadata from the page.
     pub metadata: DocumentMetadata,
 
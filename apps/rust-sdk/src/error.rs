@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -30,6 +31,7 @@ impl Display for FirecrawlAPIError {
 
 #[derive(Error, Debug)]
 pub enum FirecrawlError {
+    /// Action, status code, and the status text followed by the request's URL path.
     #[error("{0} failed: HTTP error {1}: {2}")]
     HttpRequestFailed(String, u16, String),
     #[error("{0} failed: HTTP error: {1}")]
@@ -44,4 +46,155 @@ pub enum FirecrawlError {
     CrawlJobFailed(String, CrawlStatus),
     #[error("Missuse: {0}")]
     Missuse(String),
+    /// A requested format the response did not include, e.g. `"markdown"`.
+    #[error("Response is missing the requested {0} format")]
+    MissingFormat(String),
+    #[error("{0} failed: unauthorized: {1}")]
+    Unauthorized(String, String),
+    #[error("{0} failed: forbidden: {1}")]
+    Forbidden(String, String),
+    #[error("{0} failed: request timed out")]
+    Timeout(String),
+    /// A screenshot or PDF returned as base64 data that could not be decoded.
+    #[error("Failed to decode artifact: {0}")]
+    ArtifactDecodeError(base64::DecodeError),
+    #[error("{0} failed: {1}")]
+    IoError(String, std::io::Error),
+    /// Action and warnings of a response rejected by `ClientConfig::strict_warnings`.
+    #[error("{0} failed: response carried warnings: {}", .1.join("; "))]
+    StrictWarnings(String, Vec<String>),
+    /// Action and the `Content-Type` of a response in an unexpected format.
+    #[error("{0} failed: unexpected content type {1:?}")]
+    UnexpectedContentType(String, String),
+    #[error("Failed to serialize request: {0}")]
+    RequestSerializeError(serde_json::Error),
+    /// An error shared by every caller of a coalesced request, for errors that
+    /// cannot be rebuilt for each caller. See `FirecrawlError::from_shared`.
+    #[error(transparent)]
+    Shared(Arc<FirecrawlError>),
+}
+
+impl FirecrawlError {
+    /// Rebuilds an error shared by several callers for one of them. Errors that
+    /// only hold cloneable data come back as the same variant for every caller;
+    /// those wrapping a `reqwest` or `serde_json` error are always `Shared`.
+    pub(crate) fn from_shared(error: &Arc<FirecrawlError>) -> Self {
+        match error.as_ref() {
+            FirecrawlError::HttpRequestFailed(action, status, message) => {
+                FirecrawlError::HttpRequestFailed(action.clone(), *status, message.clone())
+            }
+            FirecrawlError::APIError(action, api_error) => {
+                FirecrawlError::APIError(action.clone(), api_error.clone())
+            }
+            FirecrawlError::CrawlJobFailed(message, status) => {
+                FirecrawlError::CrawlJobFailed(message.clone(), status.clone())
+            }
+            FirecrawlError::Missuse(message) => FirecrawlError::Missuse(message.clone()),
+            FirecrawlError::MissingFormat(format) => FirecrawlError::MissingFormat(format.clone()),
+            FirecrawlError::Unauthorized(action, message) => {
+                FirecrawlError::Unauthorized(action.clone(), message.clone())
+            }
+            FirecrawlError::Forbidden(action, message) => {
+                FirecrawlError::Forbidden(action.clone(), message.clone())
+            }
+            FirecrawlError::Timeout(action) => FirecrawlError::Timeout(action.clone()),
+            FirecrawlError::ArtifactDecodeError(e) => {
+                FirecrawlError::ArtifactDecodeError(e.clone())
+            }
+            FirecrawlError::IoError(action, e) => FirecrawlError::IoError(
+                action.clone(),
+                std::io::Error::new(e.kind(), e.to_string()),
+            ),
+            FirecrawlError::StrictWarnings(action, warnings) => {
+                FirecrawlError::StrictWarnings(action.clone(), warnings.clone())
+            }
+            FirecrawlError::UnexpectedContentType(action, content_type) => {
+                FirecrawlError::UnexpectedContentType(action.clone(), content_type.clone())
+            }
+            FirecrawlError::Shared(inner) => FirecrawlError::Shared(inner.clone()),
+            FirecrawlError::HttpError(..)
+            | FirecrawlError::ResponseParseErrorText(_)
+            | FirecrawlError::ResponseParseError(_)
+            | FirecrawlError::RequestSerializeError(_) => FirecrawlError::Shared(error.clone()),
+        }
+    }
+
+    /// Builds `HttpRequestFailed` for a non-success response. Only the URL path is
+    /// kept, so query strings never end up in logs.
+    pub(crate) fn from_status(
+        action: String,
+        status: reqwest::StatusCode,
+        url: &reqwest::Url,
+    ) -> Self {
+        FirecrawlError::HttpRequestFailed(
+            action,
+            status.as_u16(),
+            format!(
+                "{} ({})",
+                status.canonical_reason().unwrap_or("Unknown Status"),
+                url.path()
+            ),
+        )
+    }
+
+    /// Wraps an error from sending a request, separating client-side timeouts
+    /// from other transport failures.
+    pub(crate) fn from_reqwest(action: String, error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            FirecrawlError::Timeout(action)
+        } else {
+            FirecrawlError::HttpError(action, error)
+        }
+    }
+
+    /// Maps 401/403 responses to `Unauthorized`/`Forbidden`, using the API's
+    /// error message from the body when there is one.
+    pub(crate) fn from_auth_status(
+        action: &str,
+        status: reqwest::StatusCode,
+        body: &str,
+    ) -> Option<Self> {
+        let message = || {
+            serde_json::from_str::<Value>(body)
+                .ok()
+                .and_then(|value| value["error"].as_str().map(str::to_string))
+                .unwrap_or_else(|| status.canonical_reason().unwrap_or_default().to_string())
+        };
+
+        match status {
+            reqwest::StatusCode::UNAUTHORIZED => {
+                Some(FirecrawlError::Unauthorized(action.to_string(), message()))
+            }
+            reqwest::StatusCode::FORBIDDEN => {
+                Some(FirecrawlError::Forbidden(action.to_string(), message()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Builds the error for an error-status response that has no `success`
+    /// field, using its `error` or `message` field when present.
+    pub(crate) fn from_error_body(
+        action: &str,
+        status: reqwest::StatusCode,
+        url: &reqwest::Url,
+        body: &Value,
+    ) -> Self {
+        let message = body
+            .get("error")
+            .or_else(|| body.get("message"))
+            .and_then(Value::as_str);
+
+        match message {
+            Some(message) => FirecrawlError::APIError(
+                action.to_string(),
+                FirecrawlAPIError {
+                    success: false,
+                    error: message.to_string(),
+                    details: body.get("details").cloned(),
+                },
+            ),
+            None => FirecrawlError::from_status(action.to_string(), status, url),
+        }
+    }
 }
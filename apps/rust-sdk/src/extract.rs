@@ -291,7 +291,7 @@ impl FirecrawlApp {
             .json(&params)
             .send()
             .await
-            .map_err(|e| FirecrawlError::HttpError("Initiating extract job".to_string(), e))?;
+            .map_err(|e| FirecrawlError::from_reqwest("Initiating extract job".to_string(), e))?;
 
         self.handle_response(response, "initiate extract job").await
     }
@@ -321,7 +321,10 @@ impl FirecrawlApp {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Checking status of extract {}", id.as_ref()), e)
+                FirecrawlError::from_reqwest(
+                    format!("Checking status of extract {}", id.as_ref()),
+                    e,
+                )
             })?;
 
         self.handle_response(
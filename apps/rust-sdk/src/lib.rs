@@ -85,10 +85,18 @@ impl FirecrawlApp {
             ));
         }
 
+        // The v2 client negotiates compression; keep v1 requests uncompressed
+        // as they always were, even though reqwest's gzip/brotli features are on
+        let client = Client::builder()
+            .gzip(false)
+            .brotli(false)
+            .build()
+            .map_err(|e| FirecrawlError::from_reqwest("Building HTTP client".to_string(), e))?;
+
         Ok(FirecrawlApp {
             api_key: api_key.map(|x| x.as_ref().to_string()),
             api_url: url,
-            client: Client::new(),
+            client,
         })
     }
 
@@ -107,35 +115,53 @@ impl FirecrawlApp {
         headers
     }
 
-    async fn handle_response<'a, T: DeserializeOwned>(
+    async fn handle_response<T: DeserializeOwned>(
         &self,
         response: Response,
         action: impl AsRef<str>,
     ) -> Result<T, FirecrawlError> {
         let (is_success, status) = (response.status().is_success(), response.status());
+        let url = response.url().clone();
 
         let response = response
             .text()
             .await
-            .map_err(|e| FirecrawlError::ResponseParseErrorText(e))
+            .map_err(FirecrawlError::ResponseParseErrorText)
+            .and_then(|response_text| {
+                // Surface auth failures before the generic error handling
+                match FirecrawlError::from_auth_status(action.as_ref(), status, &response_text) {
+                    Some(error) => Err(error),
+                    None => Ok(response_text),
+                }
+            })
             .and_then(|response_json| {
                 serde_json::from_str::<Value>(&response_json)
-                    .map_err(|e| FirecrawlError::ResponseParseError(e))
+                    .map_err(FirecrawlError::ResponseParseError)
                     .inspect(|data| {
                         tracing::debug!("Response JSON: {:#?}", data);
                     })
             })
             .and_then(|response_value| {
+                // An error status without a success field is still an error, whatever its shape
+                if !is_success && response_value.get("success").is_none() {
+                    return Err(FirecrawlError::from_error_body(
+                        action.as_ref(),
+                        status,
+                        &url,
+                        &response_value,
+                    ));
+                }
+
                 if action.as_ref().starts_with("crawl_") // no success in check/cancel crawl responses
                     || response_value["success"].as_bool().unwrap_or(false)
                 {
                     Ok(serde_json::from_value::<T>(response_value)
-                        .map_err(|e| FirecrawlError::ResponseParseError(e))?)
+                        .map_err(FirecrawlError::ResponseParseError)?)
                 } else {
                     Err(FirecrawlError::APIError(
                         action.as_ref().to_string(),
                         serde_json::from_value(response_value)
-                            .map_err(|e| FirecrawlError::ResponseParseError(e))?,
+                            .map_err(FirecrawlError::ResponseParseError)?,
                     ))
                 }
             });
@@ -147,10 +173,10 @@ impl FirecrawlApp {
                 if is_success {
                     response
                 } else {
-                    Err(FirecrawlError::HttpRequestFailed(
+                    Err(FirecrawlError::from_status(
                         action.as_ref().to_string(),
-                        status.as_u16(),
-                        status.as_str().to_string(),
+                        status,
+                        &url,
                     ))
                 }
             }
@@ -131,7 +131,7 @@ impl FirecrawlApp {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError("Initiating LLMs.txt generation".to_string(), e)
+                FirecrawlError::from_reqwest("Initiating LLMs.txt generation".to_string(), e)
             })?;
 
         self.handle_response(response, "initiate LLMs.txt generation")
@@ -164,7 +164,7 @@ impl FirecrawlApp {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(
+                FirecrawlError::from_reqwest(
                     format!("Checking status of LLMs.txt generation {}", id.as_ref()),
                     e,
                 )
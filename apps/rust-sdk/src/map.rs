@@ -66,12 +66,12 @@ impl FirecrawlApp {
 
         let response = self
             .client
-            .post(&format!("{}{}/map", self.api_url, API_VERSION))
+            .post(format!("{}{}/map", self.api_url, API_VERSION))
             .headers(headers)
             .json(&body)
             .send()
             .await
-            .map_err(|e| FirecrawlError::HttpError(format!("Mapping {:?}", url.as_ref()), e))?;
+            .map_err(|e| FirecrawlError::from_reqwest(format!("Mapping {:?}", url.as_ref()), e))?;
 
         let response = self
             .handle_response::<MapResponse>(response, "scrape URL")
@@ -3,7 +3,8 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::{document::Document, FirecrawlApp, FirecrawlError, API_VERSION};
+pub use crate::v2::{Action, ScrollDirection};
+use crate::{document::Document, map::Location, FirecrawlApp, FirecrawlError, API_VERSION};
 
 #[derive(Deserialize, Serialize, Clone, Copy, Debug)]
 pub enum ScrapeFormats {
@@ -75,6 +76,20 @@ pub struct AgentOptions {
     pub wait_before_closing_ms: Option<u32>,
 }
 
+/// Proxy to route the scrape through.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProxyMode {
+    /// Standard proxies, suitable for most sites.
+    Basic,
+
+    /// Stealth proxies for sites with advanced anti-bot protection. Slower and more expensive.
+    Stealth,
+
+    /// Retry with stealth proxies if the basic proxy fails.
+    Auto,
+}
+
 #[serde_with::skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -101,6 +116,9 @@ pub struct ScrapeOptions {
     // Amount of time to wait after loading the page, and before grabbing the content, in milliseconds. (default: `0`)
     pub wait_for: Option<u32>,
 
+    /// Emulate a mobile device when loading the page. (default: `false`)
+    pub mobile: Option<bool>,
+
     // Timeout before returning an error, in milliseconds. (default: `60000`)
     pub timeout: Option<u32>,
 
@@ -109,6 +127,18 @@ pub struct ScrapeOptions {
 
     /// Agent options for smart scrape.
     pub agent: Option<AgentOptions>,
+
+    /// Actions to perform on the page before grabbing the content, in order.
+    pub actions: Option<Vec<Action>>,
+
+    /// Proxy to use when loading the page. (default: `Basic`)
+    pub proxy: Option<ProxyMode>,
+
+    /// Location to emulate when loading the page, e.g. to reach geo-fenced content from a specific country.
+    pub location: Option<Location>,
+
+    /// Accept a cached result up to this many milliseconds old instead of scraping the page again. (default: `0`)
+    pub max_age: Option<u32>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Default)]
@@ -147,12 +177,12 @@ impl FirecrawlApp {
 
         let response = self
             .client
-            .post(&format!("{}{}/scrape", self.api_url, API_VERSION))
+            .post(format!("{}{}/scrape", self.api_url, API_VERSION))
             .headers(headers)
             .json(&body)
             .send()
             .await
-            .map_err(|e| FirecrawlError::HttpError(format!("Scraping {:?}", url.as_ref()), e))?;
+            .map_err(|e| FirecrawlError::from_reqwest(format!("Scraping {:?}", url.as_ref()), e))?;
 
         let response = self
             .handle_response::<ScrapeResponse>(response, "scrape URL")
@@ -161,3 +191,206 @@ impl FirecrawlApp {
         Ok(response.data)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_actions_serialize_in_order() {
+        let options = ScrapeOptions {
+            actions: Some(vec![
+                Action::Wait {
+                    milliseconds: Some(500),
+                    selector: None,
+                },
+                Action::Click {
+                    selector: "#accept".to_string(),
+                },
+                Action::Write {
+                    text: "firecrawl".to_string(),
+                },
+                Action::Press {
+                    key: "Enter".to_string(),
+                },
+                Action::Scroll {
+                    direction: ScrollDirection::Down,
+                    selector: None,
+                },
+                Action::Screenshot {
+                    full_page: None,
+                    quality: None,
+                    viewport: None,
+                },
+            ]),
+            ..Default::default()
+        };
+
+        let body = serde_json::to_value(ScrapeRequestBody {
+            url: "https://example.com".to_string(),
+            options,
+        })
+        .unwrap();
+
+        assert_eq!(
+            body,
+            json!({
+                "url": "https://example.com",
+                "actions": [
+                    { "type": "wait", "milliseconds": 500 },
+                    { "type": "click", "selector": "#accept" },
+                    { "type": "write", "text": "firecrawl" },
+                    { "type": "press", "key": "Enter" },
+                    { "type": "scroll", "direction": "down" },
+                    { "type": "screenshot" }
+                ]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scrape_url_with_json_options() {
+        let mut server = mockito::Server::new_async().await;
+
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "title": { "type": "string" },
+                "price": { "type": "number" }
+            }
+        });
+
+        let mock = server
+            .mock("POST", "/v1/scrape")
+            .match_body(mockito::Matcher::Json(json!({
+                "url": "https://example.com/product",
+                "formats": ["json"],
+                "jsonOptions": {
+                    "schema": schema,
+                    "prompt": "Extract the product"
+                }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "json": { "title": "Product Name", "price": 99.99 },
+                        "metadata": {
+                            "sourceURL": "https://example.com/product",
+                            "statusCode": 200
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let app = FirecrawlApp::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = ScrapeOptions {
+            formats: Some(vec![ScrapeFormats::Json]),
+            json_options: Some(JsonOptions {
+                schema: Some(schema.clone()),
+                prompt: Some("Extract the product".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let document = app
+            .scrape_url("https://example.com/product", options)
+            .await
+            .unwrap();
+
+        let data = document.json.unwrap();
+        assert_eq!(data["title"], "Product Name");
+        assert_eq!(data["price"], 99.99);
+        mock.assert();
+    }
+
+    #[test]
+    fn test_optional_fields_omitted_when_unset() {
+        let body = serde_json::to_value(ScrapeOptions::default()).unwrap();
+        assert_eq!(body, json!({}));
+    }
+
+    #[test]
+    fn test_proxy_and_location_serialization() {
+        let options = ScrapeOptions {
+            proxy: Some(ProxyMode::Stealth),
+            location: Some(Location {
+                country: Some("de".to_string()),
+                languages: None,
+            }),
+            ..Default::default()
+        };
+
+        let body = serde_json::to_value(&options).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "proxy": "stealth",
+                "location": { "country": "de" }
+            })
+        );
+
+        let params = crate::search::SearchParams {
+            query: "test".to_string(),
+            scrape_options: Some(options),
+            ..Default::default()
+        };
+        let body = serde_json::to_value(&params).unwrap();
+        assert_eq!(body["scrapeOptions"]["proxy"], "stealth");
+        assert_eq!(body["scrapeOptions"]["location"]["country"], "de");
+    }
+
+    #[test]
+    fn test_wait_for_and_mobile_serialization() {
+        let options = ScrapeOptions {
+            wait_for: Some(2000),
+            mobile: Some(true),
+            ..Default::default()
+        };
+        let body = serde_json::to_value(&options).unwrap();
+        assert_eq!(body, json!({ "waitFor": 2000, "mobile": true }));
+
+        let options = ScrapeOptions {
+            wait_for: Some(500),
+            ..Default::default()
+        };
+        let body = serde_json::to_value(&options).unwrap();
+        assert_eq!(body, json!({ "waitFor": 500 }));
+    }
+
+    #[test]
+    fn test_max_age_serialization() {
+        let options = ScrapeOptions {
+            max_age: Some(86_400_000),
+            ..Default::default()
+        };
+        let body = serde_json::to_value(&options).unwrap();
+        assert_eq!(body, json!({ "maxAge": 86_400_000 }));
+    }
+
+    #[test]
+    fn test_content_filter_serialization() {
+        let options = ScrapeOptions {
+            only_main_content: Some(false),
+            include_tags: Some(vec!["article".to_string(), ".content".to_string()]),
+            exclude_tags: Some(vec!["nav".to_string(), "#ads".to_string()]),
+            ..Default::default()
+        };
+
+        let body = serde_json::to_value(&options).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "onlyMainContent": false,
+                "includeTags": ["article", ".content"],
+                "excludeTags": ["nav", "#ads"]
+            })
+        );
+    }
+}
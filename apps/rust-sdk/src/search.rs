@@ -114,7 +114,7 @@ impl FirecrawlApp {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Searching with query: {:?}", params.query), e)
+                FirecrawlError::from_reqwest(format!("Searching with query: {:?}", params.query), e)
             })?;
 
         self.handle_response::<SearchResponse>(response, "search")
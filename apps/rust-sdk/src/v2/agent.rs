@@ -6,7 +6,7 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::client::Client;
+use super::client::{json_body, Client};
 use super::types::{AgentModel, AgentWebhookConfig};
 use crate::FirecrawlError;
 
@@ -139,10 +139,10 @@ impl Client {
             .client
             .post(self.url("/agent"))
             .headers(headers)
-            .json(&options)
+            .json(&json_body(&options, None)?)
             .send()
             .await
-            .map_err(|e| FirecrawlError::HttpError("Starting agent task".to_string(), e))?;
+            .map_err(|e| FirecrawlError::from_reqwest("Starting agent task".to_string(), e))?;
 
         self.handle_response(response, "start agent").await
     }
@@ -187,7 +187,7 @@ impl Client {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Getting agent status {}", id.as_ref()), e)
+                FirecrawlError::from_reqwest(format!("Getting agent status {}", id.as_ref()), e)
             })?;
 
         self.handle_response(response, format!("agent status {}", id.as_ref()))
@@ -325,7 +325,7 @@ impl Client {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Cancelling agent {}", id.as_ref()), e)
+                FirecrawlError::from_reqwest(format!("Cancelling agent {}", id.as_ref()), e)
             })?;
 
         #[derive(Deserialize)]
@@ -1,10 +1,13 @@
 //! Batch scrape endpoint for Firecrawl API v2.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
-use super::client::Client;
+use super::client::{json_body, Client};
 use super::scrape::ScrapeOptions;
-use super::types::{CrawlErrorsResponse, Document, JobStatus, WebhookConfig};
+use super::types::{normalize_host, CrawlErrorsResponse, Document, JobStatus, WebhookConfig};
 use crate::FirecrawlError;
 
 /// Options for batch scraping.
@@ -29,7 +32,8 @@ pub struct BatchScrapeOptions {
     /// Maximum concurrent requests.
     pub max_concurrency: Option<u32>,
 
-    /// Enable zero data retention mode.
+    /// Enable zero data retention mode. May also be set on the scrape
+    /// `options`; setting both to different values is rejected.
     pub zero_data_retention: Option<bool>,
 
     /// Idempotency key for the request.
@@ -68,6 +72,21 @@ pub struct BatchScrapeResponse {
     pub invalid_urls: Option<Vec<String>>,
 }
 
+/// Outcome for one URL of [`Client::batch_scrape_mixed`].
+#[derive(Debug)]
+pub struct MixedBatchScrapeItem {
+    /// The input URL.
+    pub url: String,
+    /// ID of the batch job the URL was submitted in, or `None` if starting the
+    /// job failed.
+    pub job_id: Option<String>,
+    /// The scraped document, `None` if the job completed without a document
+    /// for this URL, or the job's error. Every URL of a failed job gets the same
+    /// error variant; errors that cannot be cloned are wrapped in
+    /// `FirecrawlError::Shared`.
+    pub result: Result<Option<Document>, FirecrawlError>,
+}
+
 /// Status of a batch scrape job.
 #[serde_with::skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -132,7 +151,23 @@ impl Client {
         urls: Vec<String>,
         options: impl Into<Option<BatchScrapeOptions>>,
     ) -> Result<BatchScrapeResponse, FirecrawlError> {
-        let options = options.into().unwrap_or_default();
+        let mut options = options.into().unwrap_or_default();
+        // Both levels serialize as `zeroDataRetention`; keep a single key
+        if let Some(zdr) = options
+            .options
+            .as_mut()
+            .and_then(|o| o.zero_data_retention.take())
+        {
+            if *options.zero_data_retention.get_or_insert(zdr) != zdr {
+                return Err(FirecrawlError::Missuse(
+                    "zero_data_retention is set to different values on the batch and its scrape options"
+                        .to_string(),
+                ));
+            }
+        }
+        // The scrape options are flattened, so their extra fields go on the top
+        // level, below the batch fields
+        let extra = options.options.as_mut().and_then(|o| o.extra.take());
         let body = BatchScrapeRequest {
             urls,
             options: options.clone(),
@@ -144,10 +179,10 @@ impl Client {
             .client
             .post(self.url("/batch/scrape"))
             .headers(headers)
-            .json(&body)
+            .json(&json_body(&body, extra.as_ref())?)
             .send()
             .await
-            .map_err(|e| FirecrawlError::HttpError("Starting batch scrape".to_string(), e))?;
+            .map_err(|e| FirecrawlError::from_reqwest("Starting batch scrape".to_string(), e))?;
 
         self.handle_response(response, "start batch scrape").await
     }
@@ -192,7 +227,7 @@ impl Client {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(
+                FirecrawlError::from_reqwest(
                     format!("Checking batch scrape status {}", id.as_ref()),
                     e,
                 )
@@ -226,7 +261,7 @@ impl Client {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Paginating batch scrape at {}", next), e)
+                FirecrawlError::from_reqwest(format!("Paginating batch scrape at {}", next), e)
             })?;
 
         self.handle_response(response, "batch scrape pagination")
@@ -294,6 +329,163 @@ impl Client {
             .await
     }
 
+    /// Batch scrapes URLs that each carry their own scrape options.
+    ///
+    /// A batch scrape job applies one set of options to all of its URLs, so the
+    /// items are grouped by identical options and each group is submitted as its
+    /// own batch job. The jobs run concurrently and this waits for all of them,
+    /// even if some fail, so no started job goes unreported.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - URLs paired with the scrape options to use for them.
+    ///
+    /// # Returns
+    ///
+    /// One `MixedBatchScrapeItem` per input URL, in the order of `items`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if the options cannot be serialized; job failures
+    /// are reported per item.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::{Client, ScrapeOptions, Format};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("your-api-key")?;
+    ///
+    ///     let markdown = ScrapeOptions {
+    ///         formats: Some(vec![Format::Markdown]),
+    ///         ..Default::default()
+    ///     };
+    ///     let links = ScrapeOptions {
+    ///         formats: Some(vec![Format::Links]),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let items = client
+    ///         .batch_scrape_mixed(vec![
+    ///             ("https://example.com/blog".to_string(), markdown),
+    ///             ("https://example.com/sitemap".to_string(), links),
+    ///         ])
+    ///         .await?;
+    ///
+    ///     for item in items {
+    ///         match item.result {
+    ///             Ok(doc) => println!("{}: scraped {}", item.url, doc.is_some()),
+    ///             Err(e) => eprintln!("{} (job {:?}): {}", item.url, item.job_id, e),
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn batch_scrape_mixed(
+        &self,
+        items: Vec<(String, ScrapeOptions)>,
+    ) -> Result<Vec<MixedBatchScrapeItem>, FirecrawlError> {
+        // Group item indices by serialized options, keeping first-appearance
+        // order. Going through `Value` sorts map keys, so equal options give
+        // equal keys.
+        let mut groups: Vec<(String, &ScrapeOptions, Vec<usize>)> = Vec::new();
+        for (index, (_, options)) in items.iter().enumerate() {
+            let key = serde_json::to_value(options)
+                .map_err(FirecrawlError::RequestSerializeError)?
+                .to_string();
+            match groups.iter_mut().find(|(existing, _, _)| *existing == key) {
+                Some((_, _, indices)) => indices.push(index),
+                None => groups.push((key, options, vec![index])),
+            }
+        }
+
+        let jobs = groups.into_iter().map(|(_, options, indices)| {
+            let urls = indices.iter().map(|&i| items[i].0.clone()).collect();
+            async move {
+                let outcome = self.run_mixed_batch_job(urls, options.clone()).await;
+                (indices, outcome)
+            }
+        });
+
+        // Every index belongs to exactly one job, so this yields one item per input
+        let mut results = Vec::with_capacity(items.len());
+        for (indices, (job_id, job)) in futures::future::join_all(jobs).await {
+            match job {
+                Ok(job) => {
+                    let documents: HashMap<String, Document> = job
+                        .data
+                        .into_iter()
+                        .filter_map(|doc| {
+                            let source_url = doc.metadata.as_ref()?.source_url.as_deref()?;
+                            Some((source_url_key(source_url), doc))
+                        })
+                        .collect();
+                    for i in indices {
+                        let url = items[i].0.clone();
+                        let document = documents.get(&source_url_key(&url)).cloned();
+                        let result = Ok(document);
+                        let job_id = job_id.clone();
+                        results.push((
+                            i,
+                            MixedBatchScrapeItem {
+                                url,
+                                job_id,
+                                result,
+                            },
+                        ));
+                    }
+                }
+                Err(error) => {
+                    // Every URL of the job gets the same error
+                    let error = Arc::new(error);
+                    for i in indices {
+                        let url = items[i].0.clone();
+                        let result = Err(FirecrawlError::from_shared(&error));
+                        let job_id = job_id.clone();
+                        results.push((
+                            i,
+                            MixedBatchScrapeItem {
+                                url,
+                                job_id,
+                                result,
+                            },
+                        ));
+                    }
+                }
+            }
+        }
+
+        results.sort_by_key(|(i, _)| *i);
+        Ok(results.into_iter().map(|(_, item)| item).collect())
+    }
+
+    /// Starts a batch scrape job and waits for it, returning the job id once it
+    /// is known so that callers can still find a job that failed later on.
+    async fn run_mixed_batch_job(
+        &self,
+        urls: Vec<String>,
+        options: ScrapeOptions,
+    ) -> (Option<String>, Result<BatchScrapeJob, FirecrawlError>) {
+        let options = BatchScrapeOptions {
+            options: Some(options),
+            ..Default::default()
+        };
+        let poll_interval = options.poll_interval.unwrap_or(2000);
+
+        match self.start_batch_scrape(urls, options).await {
+            Ok(response) => {
+                let job = self
+                    .wait_for_batch_scrape(&response.id, poll_interval)
+                    .await;
+                (Some(response.id), job)
+            }
+            Err(error) => (None, Err(error)),
+        }
+    }
+
     /// Waits for a batch scrape job to complete.
     async fn wait_for_batch_scrape(
         &self,
@@ -362,7 +554,10 @@ impl Client {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Getting batch scrape errors {}", id.as_ref()), e)
+                FirecrawlError::from_reqwest(
+                    format!("Getting batch scrape errors {}", id.as_ref()),
+                    e,
+                )
             })?;
 
         self.handle_response(response, "batch scrape errors").await
@@ -386,7 +581,7 @@ fn convert_batch_job_to_crawl_status(job: BatchScrapeJob) -> crate::crawl::Crawl
         data: job
             .data
             .into_iter()
-            .map(|d| super::crawl::convert_v2_document_to_v1_pub(d))
+            .map(super::crawl::convert_v2_document_to_v1_pub)
             .collect(),
     }
 }
@@ -398,6 +593,33 @@ impl super::crawl::CrawlJob {
     }
 }
 
+/// Key matching a document's `sourceURL` to the input URL it was scraped for,
+/// ignoring what the server may normalize: the scheme, host case and a leading
+/// `www.`, default ports, the fragment and a trailing slash.
+fn source_url_key(url: &str) -> String {
+    match reqwest::Url::parse(url.trim()) {
+        Ok(parsed) => {
+            let host = parsed.host_str().map(normalize_host).unwrap_or_default();
+            let port = parsed
+                .port()
+                .map(|port| format!(":{}", port))
+                .unwrap_or_default();
+            let query = parsed
+                .query()
+                .map(|query| format!("?{}", query))
+                .unwrap_or_default();
+            format!(
+                "{}{}{}{}",
+                host,
+                port,
+                parsed.path().trim_end_matches('/'),
+                query
+            )
+        }
+        Err(_) => url.trim().trim_end_matches('/').to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -558,6 +780,261 @@ mod tests {
         status_mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_start_batch_scrape_zero_data_retention_sent_once() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/batch/scrape")
+            .match_body(mockito::Matcher::Exact(
+                json!({ "urls": ["https://example.com"], "zeroDataRetention": true }).to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "success": true, "id": "batch-zdr", "url": "https://api.firecrawl.dev/v2/batch/scrape/batch-zdr" })
+                    .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = BatchScrapeOptions {
+            options: Some(ScrapeOptions {
+                zero_data_retention: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let response = client
+            .start_batch_scrape(vec!["https://example.com".to_string()], options)
+            .await
+            .unwrap();
+
+        assert_eq!(response.id, "batch-zdr");
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_start_batch_scrape_rejects_conflicting_zero_data_retention() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server.mock("POST", "/v2/batch/scrape").expect(0).create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = BatchScrapeOptions {
+            options: Some(ScrapeOptions {
+                zero_data_retention: Some(true),
+                ..Default::default()
+            }),
+            zero_data_retention: Some(false),
+            ..Default::default()
+        };
+        let result = client
+            .start_batch_scrape(vec!["https://example.com".to_string()], options)
+            .await;
+
+        assert!(matches!(result, Err(FirecrawlError::Missuse(_))));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_batch_scrape_mixed() {
+        use crate::v2::Format;
+
+        let mut server = mockito::Server::new_async().await;
+
+        let markdown_mock = server
+            .mock("POST", "/v2/batch/scrape")
+            .match_body(mockito::Matcher::Json(json!({
+                "urls": ["https://example.com/a", "https://example.com/c"],
+                "formats": ["markdown"]
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "success": true, "id": "batch-md", "url": "https://api.firecrawl.dev/v2/batch/scrape/batch-md" })
+                    .to_string(),
+            )
+            .create();
+
+        let links_mock = server
+            .mock("POST", "/v2/batch/scrape")
+            .match_body(mockito::Matcher::Json(json!({
+                "urls": ["https://example.com/b"],
+                "formats": ["links"],
+                "onlyMainContent": false
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "success": true, "id": "batch-links", "url": "https://api.firecrawl.dev/v2/batch/scrape/batch-links" })
+                    .to_string(),
+            )
+            .create();
+
+        let rejected_mock = server
+            .mock("POST", "/v2/batch/scrape")
+            .match_body(mockito::Matcher::PartialJson(
+                json!({ "formats": ["html"] }),
+            ))
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": false, "error": "Invalid options" }).to_string())
+            .create();
+
+        let markdown_status_mock = server
+            .mock("GET", "/v2/batch/scrape/batch-md")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "completed",
+                    "total": 2,
+                    "completed": 2,
+                    "data": [
+                        { "markdown": "# C", "metadata": { "sourceURL": "https://example.com/c" } },
+                        { "markdown": "# A", "metadata": { "sourceURL": "https://example.com/a" } }
+                    ]
+                })
+                .to_string(),
+            )
+            .create();
+        let links_status_mock = server
+            .mock("GET", "/v2/batch/scrape/batch-links")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "status": "failed", "total": 1, "completed": 0, "data": [] }).to_string(),
+            )
+            .create();
+
+        let options = |format, only_main_content| ScrapeOptions {
+            formats: Some(vec![format]),
+            only_main_content,
+            ..Default::default()
+        };
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let items = client
+            .batch_scrape_mixed(vec![
+                (
+                    "https://example.com/a".to_string(),
+                    options(Format::Markdown, None),
+                ),
+                (
+                    "https://example.com/b".to_string(),
+                    options(Format::Links, Some(false)),
+                ),
+                (
+                    "https://example.com/c".to_string(),
+                    options(Format::Markdown, None),
+                ),
+                (
+                    "https://example.com/d".to_string(),
+                    options(Format::Html, None),
+                ),
+                (
+                    "https://example.com/e".to_string(),
+                    options(Format::Html, None),
+                ),
+            ])
+            .await
+            .unwrap();
+
+        let urls: Vec<_> = items.iter().map(|item| item.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://example.com/a",
+                "https://example.com/b",
+                "https://example.com/c",
+                "https://example.com/d",
+                "https://example.com/e"
+            ]
+        );
+
+        // Documents are matched back to their URLs
+        for (item, markdown) in [(&items[0], "# A"), (&items[2], "# C")] {
+            assert_eq!(item.job_id.as_deref(), Some("batch-md"));
+            let doc = item.result.as_ref().unwrap().as_ref().unwrap();
+            assert_eq!(doc.markdown.as_deref(), Some(markdown));
+        }
+
+        // A job that failed after starting still reports its id
+        assert_eq!(items[1].job_id.as_deref(), Some("batch-links"));
+        assert!(matches!(
+            items[1].result,
+            Err(FirecrawlError::CrawlJobFailed(_, _))
+        ));
+
+        // A job that could not be started has no id, and all its URLs get the same error
+        for item in &items[3..] {
+            assert_eq!(item.job_id, None);
+            assert!(matches!(item.result, Err(FirecrawlError::APIError(_, _))));
+        }
+
+        markdown_mock.assert();
+        links_mock.assert();
+        rejected_mock.assert();
+        markdown_status_mock.assert();
+        links_status_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_batch_scrape_mixed_normalized_source_url() {
+        let mut server = mockito::Server::new_async().await;
+
+        let start_mock = server
+            .mock("POST", "/v2/batch/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "success": true, "id": "batch-1", "url": "https://api.firecrawl.dev/v2/batch/scrape/batch-1" })
+                    .to_string(),
+            )
+            .create();
+        let status_mock = server
+            .mock("GET", "/v2/batch/scrape/batch-1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "completed",
+                    "total": 1,
+                    "completed": 1,
+                    "data": [
+                        { "markdown": "# Docs", "metadata": { "sourceURL": "https://example.com/docs/" } }
+                    ]
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let items = client
+            .batch_scrape_mixed(vec![(
+                "https://example.com/docs".to_string(),
+                ScrapeOptions::default(),
+            )])
+            .await
+            .unwrap();
+
+        // The returned sourceURL only differs by a trailing slash
+        let doc = items[0].result.as_ref().unwrap().as_ref().unwrap();
+        assert_eq!(doc.markdown.as_deref(), Some("# Docs"));
+
+        assert_eq!(
+            source_url_key("https://example.com/docs"),
+            source_url_key("http://WWW.Example.com/docs/#top")
+        );
+        assert_ne!(
+            source_url_key("https://example.com/docs"),
+            source_url_key("https://example.com/docs?page=2")
+        );
+
+        start_mock.assert();
+        status_mock.assert();
+    }
+
     #[tokio::test]
     async fn test_get_batch_scrape_errors() {
         let mut server = mockito::Server::new_async().await;
@@ -0,0 +1,163 @@
+//! Client-side scrape cache for Firecrawl API v2.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use super::types::Document;
+
+/// A store for scraped documents, used by [`Client::scrape`] when set as
+/// `ClientConfig::scrape_cache`.
+///
+/// Keys combine the normalized URL and a hash of the serialized scrape
+/// options, so documents are only reused for identical requests. Implementations decide
+/// how long entries stay valid.
+///
+/// [`Client::scrape`]: super::Client::scrape
+pub trait ScrapeCache: fmt::Debug + Send + Sync {
+    /// Returns the cached document for `key`, if present and still valid.
+    fn get(&self, key: &str) -> Option<Document>;
+
+    /// Stores a freshly scraped document under `key`.
+    fn insert(&self, key: String, document: Document);
+}
+
+/// An in-memory [`ScrapeCache`] whose entries expire after a fixed TTL.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use firecrawl::v2::{Client, ClientConfig, MemoryScrapeCache};
+///
+/// let client = Client::new("your-api-key")
+///     .unwrap()
+///     .with_config(ClientConfig {
+///         scrape_cache: Some(Arc::new(MemoryScrapeCache::new(Duration::from_secs(600)))),
+///         ..Default::default()
+///     })
+///     .unwrap();
+/// ```
+pub struct MemoryScrapeCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, Document)>>,
+}
+
+impl MemoryScrapeCache {
+    /// Creates an empty cache whose entries are valid for `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        MemoryScrapeCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Removes all entries.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl fmt::Debug for MemoryScrapeCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self
+            .entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len();
+        f.debug_struct("MemoryScrapeCache")
+            .field("ttl", &self.ttl)
+            .field("entries", &len)
+            .finish()
+    }
+}
+
+impl ScrapeCache for MemoryScrapeCache {
+    fn get(&self, key: &str) -> Option<Document> {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        match entries.get(key) {
+            Some((stored_at, document)) if stored_at.elapsed() < self.ttl => Some(document.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: String, document: Document) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        // Drop expired entries so long-running clients don't grow without bound
+        entries.retain(|_, (stored_at, _)| stored_at.elapsed() < self.ttl);
+        entries.insert(key, (Instant::now(), document));
+    }
+}
+
+/// Builds the cache key for a scrape: the normalized URL followed by a hash of
+/// the serialized options.
+pub(crate) fn scrape_cache_key(url: &str, options: &str) -> String {
+    // Parsing lowercases the host and drops default ports; fragments never
+    // reach the server
+    let url = match reqwest::Url::parse(url.trim()) {
+        Ok(mut parsed) => {
+            parsed.set_fragment(None);
+            parsed.to_string()
+        }
+        Err(_) => url.trim().to_string(),
+    };
+
+    let mut hasher = DefaultHasher::new();
+    options.hash(&mut hasher);
+
+    format!("{}\n{:016x}", url, hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_cache_expires_entries() {
+        let document = Document {
+            markdown: Some("# Cached".to_string()),
+            ..Default::default()
+        };
+
+        let cache = MemoryScrapeCache::new(Duration::from_secs(60));
+        cache.insert("key".to_string(), document.clone());
+        assert_eq!(
+            cache.get("key").and_then(|doc| doc.markdown),
+            Some("# Cached".to_string())
+        );
+        assert!(cache.get("other").is_none());
+        cache.clear();
+        assert!(cache.get("key").is_none());
+
+        let cache = MemoryScrapeCache::new(Duration::ZERO);
+        cache.insert("key".to_string(), document);
+        assert!(cache.get("key").is_none());
+    }
+
+    #[test]
+    fn test_scrape_cache_key_normalizes_url() {
+        assert_eq!(
+            scrape_cache_key("https://EXAMPLE.com:443/page#section", "{}"),
+            scrape_cache_key("https://example.com/page", "{}")
+        );
+        assert_ne!(
+            scrape_cache_key("https://example.com/page", "{}"),
+            scrape_cache_key("https://example.com/page", r#"{"mobile":true}"#)
+        );
+        assert_ne!(
+            scrape_cache_key("https://example.com/page?a=1", "{}"),
+            scrape_cache_key("https://example.com/page", "{}")
+        );
+    }
+}
@@ -1,13 +1,24 @@
 //! Firecrawl API v2 client.
 
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::stream::{self, Stream, StreamExt};
+use reqwest::header::HeaderName;
 use reqwest::Response;
 use serde::de::DeserializeOwned;
-use serde_json::Value;
+use serde::Serialize;
+use serde_json::{Map, Value};
 
+use super::cache::ScrapeCache;
+use super::search::InFlightSearches;
 use crate::error::{FirecrawlAPIError, FirecrawlError};
 
 pub(crate) const API_VERSION: &str = "/v2";
 const CLOUD_API_URL: &str = "https://api.firecrawl.dev";
+const DEFAULT_USER_AGENT: &str = concat!("firecrawl-rust-sdk/", env!("CARGO_PKG_VERSION"));
 
 /// Firecrawl API v2 client.
 ///
@@ -35,6 +46,132 @@ pub struct Client {
     pub(crate) api_key: Option<String>,
     pub(crate) api_url: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) config: ClientConfig,
+    pub(crate) request_id_header: Option<RequestIdHeader>,
+    pub(crate) in_flight_searches: InFlightSearches,
+}
+
+/// Configuration for the HTTP client used by [`Client`].
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use firecrawl::v2::{Client, ClientConfig};
+///
+/// let client = Client::new("your-api-key")
+///     .unwrap()
+///     .with_config(ClientConfig {
+///         timeout: Some(Duration::from_secs(30)),
+///         ..Default::default()
+///     })
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClientConfig {
+    /// Timeout for each HTTP request, covering connecting through reading the
+    /// response body. Requests exceeding it fail with `FirecrawlError::Timeout`.
+    /// (default: no timeout)
+    pub timeout: Option<Duration>,
+
+    /// `User-Agent` header sent with every request.
+    /// (default: `firecrawl-rust-sdk/<version>`)
+    pub user_agent: Option<String>,
+
+    /// Whether to request gzip/brotli-compressed responses and transparently
+    /// decompress them, which cuts bandwidth for large scrapes and crawls.
+    /// (default: true)
+    pub compression: bool,
+
+    /// Share one HTTP request between concurrent identical `search` calls, i.e.
+    /// calls with the same query and options. Every caller receives a clone of
+    /// the result, and every caller of a failed search the same error variant
+    /// (see `FirecrawlError::Shared`). Useful when many tasks may issue the same
+    /// query at once.
+    /// (default: false)
+    pub coalesce_searches: bool,
+
+    /// Fail scrapes and searches, including helpers such as `search_scraped`,
+    /// whose response carries a warning, e.g. because some results could not be
+    /// scraped, with
+    /// `FirecrawlError::StrictWarnings` instead of returning partial results.
+    /// (default: false)
+    pub strict_warnings: bool,
+
+    /// Cache for `scrape` results. Identical scrapes, i.e. the same normalized
+    /// URL and options, are answered from the cache while their entry is valid,
+    /// without calling the API. See [`MemoryScrapeCache`](super::MemoryScrapeCache).
+    /// Screenshot and other artifact URLs in cached documents are temporary and
+    /// may stop working before the cache entry expires. Scrapes with
+    /// `zero_data_retention` set are never read from or stored in the cache.
+    /// (default: no cache)
+    pub scrape_cache: Option<Arc<dyn ScrapeCache>>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            timeout: None,
+            user_agent: None,
+            compression: true,
+            coalesce_searches: false,
+            strict_warnings: false,
+            scrape_cache: None,
+        }
+    }
+}
+
+/// A snapshot of the settings a [`Client`] is using, safe to log.
+///
+/// Returned by [`Client::config`]. The API key is masked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientConfigView {
+    /// Base URL of the Firecrawl instance.
+    pub api_url: String,
+    /// API version path appended to the base URL, e.g. `/v2`.
+    pub base_path: String,
+    /// The API key with all but its last four characters masked, or fully
+    /// masked if shorter than 16 characters.
+    pub api_key: Option<String>,
+    /// Per-request timeout, if any.
+    pub timeout: Option<Duration>,
+    /// `User-Agent` header sent with requests.
+    pub user_agent: String,
+    /// Whether responses are requested compressed.
+    pub compression: bool,
+}
+
+impl ClientConfig {
+    /// Builds the underlying HTTP client for this configuration.
+    fn build_http_client(&self) -> Result<reqwest::Client, FirecrawlError> {
+        let mut builder = reqwest::Client::builder().user_agent(
+            self.user_agent
+                .clone()
+                .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+        );
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        builder = builder.gzip(self.compression).brotli(self.compression);
+        builder
+            .build()
+            .map_err(|e| FirecrawlError::from_reqwest("Building HTTP client".to_string(), e))
+    }
+}
+
+/// A header carrying a freshly generated id on every request.
+#[derive(Clone)]
+pub(crate) struct RequestIdHeader {
+    name: HeaderName,
+    generator: Arc<dyn Fn() -> String + Send + Sync>,
+}
+
+impl fmt::Debug for RequestIdHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestIdHeader")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Client {
@@ -68,7 +205,8 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns an error if using the cloud service without an API key.
+    /// Returns an error if `api_url` is not a valid http(s) URL, or if using the
+    /// cloud service without an API key.
     ///
     /// # Example
     ///
@@ -89,6 +227,24 @@ impl Client {
         let url = api_url.as_ref().trim_end_matches('/').to_string();
         let api_key = api_key.map(|k| k.as_ref().to_string());
 
+        // Catch typos like `localhost:3000` here rather than as opaque request errors
+        let is_valid_url = reqwest::Url::parse(&url)
+            .map(|parsed| matches!(parsed.scheme(), "http" | "https") && parsed.has_host())
+            .unwrap_or(false);
+        if !is_valid_url {
+            return Err(FirecrawlError::APIError(
+                "Configuration".to_string(),
+                FirecrawlAPIError {
+                    success: false,
+                    error: format!(
+                        "Invalid API URL {:?}: expected an http(s) URL such as \"http://localhost:3002\"",
+                        url
+                    ),
+                    details: None,
+                },
+            ));
+        }
+
         // Reject empty or missing API key for cloud service
         if url == CLOUD_API_URL {
             match &api_key {
@@ -116,13 +272,92 @@ impl Client {
             }
         }
 
+        let config = ClientConfig::default();
         Ok(Client {
             api_key,
             api_url: url,
-            client: reqwest::Client::new(),
+            client: config.build_http_client()?,
+            config,
+            request_id_header: None,
+            in_flight_searches: InFlightSearches::default(),
         })
     }
 
+    /// Returns the effective configuration of this client, with the API key masked.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::Client;
+    ///
+    /// let client = Client::new("fc-your-api-key").unwrap();
+    /// println!("{:?}", client.config());
+    /// ```
+    pub fn config(&self) -> ClientConfigView {
+        ClientConfigView {
+            api_url: self.api_url.clone(),
+            base_path: API_VERSION.to_string(),
+            api_key: self.api_key.as_deref().map(mask_api_key),
+            timeout: self.config.timeout,
+            user_agent: self
+                .config
+                .user_agent
+                .clone()
+                .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+            compression: self.config.compression,
+        }
+    }
+
+    /// Applies the given configuration, rebuilding the underlying HTTP client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built from the configuration.
+    pub fn with_config(mut self, config: ClientConfig) -> Result<Self, FirecrawlError> {
+        self.client = config.build_http_client()?;
+        self.config = config;
+        Ok(self)
+    }
+
+    /// Attaches a request-id header to every request made by this client.
+    ///
+    /// The generator is called once per request, so each call carries a fresh
+    /// id that can be used to correlate SDK calls with backend traces.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The header name, e.g. `x-request-id`.
+    /// * `generator` - Produces the header value for each request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name` is not a valid HTTP header name.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::Client;
+    ///
+    /// let client = Client::new("your-api-key")
+    ///     .unwrap()
+    ///     .with_request_id_header("x-request-id", || uuid::Uuid::new_v4().to_string())
+    ///     .unwrap();
+    /// ```
+    pub fn with_request_id_header(
+        mut self,
+        name: impl AsRef<str>,
+        generator: impl Fn() -> String + Send + Sync + 'static,
+    ) -> Result<Self, FirecrawlError> {
+        let name = HeaderName::from_bytes(name.as_ref().as_bytes()).map_err(|_| {
+            FirecrawlError::Missuse(format!("Invalid header name: {:?}", name.as_ref()))
+        })?;
+        self.request_id_header = Some(RequestIdHeader {
+            name,
+            generator: Arc::new(generator),
+        });
+        Ok(self)
+    }
+
     /// Prepares headers for API requests.
     pub(crate) fn prepare_headers(
         &self,
@@ -146,6 +381,14 @@ impl Client {
                 headers.insert("x-idempotency-key", value);
             }
         }
+        if let Some(request_id) = self.request_id_header.as_ref() {
+            // Skip ids that are not valid header values rather than failing the request
+            if let Ok(value) = (request_id.generator)().parse() {
+                headers.insert(request_id.name.clone(), value);
+            }
+        }
+        #[cfg(feature = "otel")]
+        inject_trace_context(&mut headers);
         headers
     }
 
@@ -156,11 +399,26 @@ impl Client {
         action: impl AsRef<str>,
     ) -> Result<T, FirecrawlError> {
         let (is_success, status) = (response.status().is_success(), response.status());
+        let url = response.url().clone();
 
         let response = response
             .text()
             .await
-            .map_err(FirecrawlError::ResponseParseErrorText)
+            .map_err(|e| {
+                // The client timeout also covers reading the body
+                if e.is_timeout() {
+                    FirecrawlError::from_reqwest(action.as_ref().to_string(), e)
+                } else {
+                    FirecrawlError::ResponseParseErrorText(e)
+                }
+            })
+            .and_then(|response_text| {
+                // Surface auth failures before the generic error handling
+                match FirecrawlError::from_auth_status(action.as_ref(), status, &response_text) {
+                    Some(error) => Err(error),
+                    None => Ok(response_text),
+                }
+            })
             .and_then(|response_json| {
                 serde_json::from_str::<Value>(&response_json)
                     .map_err(FirecrawlError::ResponseParseError)
@@ -169,6 +427,16 @@ impl Client {
                     })
             })
             .and_then(|response_value| {
+                // An error status without a success field is still an error, whatever its shape
+                if !is_success && response_value.get("success").is_none() {
+                    return Err(FirecrawlError::from_error_body(
+                        action.as_ref(),
+                        status,
+                        &url,
+                        &response_value,
+                    ));
+                }
+
                 // Check for success field, or allow responses without it for status checks
                 if action.as_ref().contains("status")
                     || action.as_ref().contains("cancel")
@@ -193,10 +461,10 @@ impl Client {
                 if is_success {
                     response
                 } else {
-                    Err(FirecrawlError::HttpRequestFailed(
+                    Err(FirecrawlError::from_status(
                         action.as_ref().to_string(),
-                        status.as_u16(),
-                        status.as_str().to_string(),
+                        status,
+                        &url,
                     ))
                 }
             }
@@ -204,10 +472,216 @@ impl Client {
         }
     }
 
+    /// Handles line-delimited JSON (NDJSON) responses as a stream.
+    ///
+    /// Each line is parsed into `T` as soon as it has been fully received, so
+    /// the body is never buffered as a whole. Lines split across chunks are
+    /// reassembled, and a final line without a trailing newline is still parsed.
+    /// An incomplete or malformed line yields an error item without ending the
+    /// stream; lines carrying `"success": false` are surfaced as API errors.
+    pub(crate) async fn handle_ndjson_stream<T: DeserializeOwned>(
+        &self,
+        response: Response,
+        action: impl AsRef<str>,
+    ) -> Result<impl Stream<Item = Result<T, FirecrawlError>>, FirecrawlError> {
+        let decoder = NdjsonDecoder {
+            buffer: Vec::new(),
+            action: action.as_ref().to_string(),
+            item: PhantomData,
+        };
+        self.handle_stream(response, action, decoder).await
+    }
+
+    /// Handles a streamed response, decoding items as chunks arrive.
+    ///
+    /// Non-success responses are read in full and reported like buffered ones.
+    /// A transport error while reading the body is yielded as the last item.
+    pub(crate) async fn handle_stream<D: StreamDecoder>(
+        &self,
+        response: Response,
+        action: impl AsRef<str>,
+        decoder: D,
+    ) -> Result<impl Stream<Item = Result<D::Item, FirecrawlError>>, FirecrawlError> {
+        let status = response.status();
+
+        if !status.is_success() {
+            // Error bodies are plain JSON, so reuse the buffered error handling
+            let url = response.url().clone();
+            self.handle_response::<Value>(response, &action).await?;
+            return Err(FirecrawlError::from_status(
+                action.as_ref().to_string(),
+                status,
+                &url,
+            ));
+        }
+
+        let chunks = response.bytes_stream().boxed();
+
+        Ok(stream::unfold(
+            Some((chunks, decoder, false)),
+            |state| async move {
+                let (mut chunks, mut decoder, mut eof) = state?;
+                loop {
+                    if let Some(item) = decoder.next_item(eof) {
+                        return Some((item, Some((chunks, decoder, eof))));
+                    }
+                    if eof {
+                        return None;
+                    }
+                    match chunks.next().await {
+                        Some(Ok(chunk)) => decoder.push(&chunk),
+                        Some(Err(e)) => {
+                            return Some((Err(FirecrawlError::ResponseParseErrorText(e)), None))
+                        }
+                        None => eof = true,
+                    }
+                }
+            },
+        ))
+    }
+
     /// Builds the full URL for an API endpoint.
     pub(crate) fn url(&self, path: &str) -> String {
         format!("{}{}{}", self.api_url, API_VERSION, path)
     }
+
+    /// In strict warnings mode, turns any warnings on a response into an error.
+    pub(crate) fn check_warnings(
+        &self,
+        action: &str,
+        warnings: impl IntoIterator<Item = String>,
+    ) -> Result<(), FirecrawlError> {
+        if !self.config.strict_warnings {
+            return Ok(());
+        }
+
+        let warnings: Vec<String> = warnings.into_iter().collect();
+        if warnings.is_empty() {
+            return Ok(());
+        }
+
+        Err(FirecrawlError::StrictWarnings(action.to_string(), warnings))
+    }
+}
+
+/// Serializes a request body into a JSON value, then adds the `extra` fields
+/// the body does not already set.
+pub(crate) fn json_body(
+    body: &impl Serialize,
+    extra: Option<&Map<String, Value>>,
+) -> Result<Value, FirecrawlError> {
+    let mut value = serde_json::to_value(body).map_err(FirecrawlError::RequestSerializeError)?;
+    merge_extra(&mut value, extra);
+    Ok(value)
+}
+
+/// Adds the `extra` fields missing from `value`, so typed fields always win.
+pub(crate) fn merge_extra(value: &mut Value, extra: Option<&Map<String, Value>>) {
+    if let (Value::Object(fields), Some(extra)) = (value, extra) {
+        for (key, extra_value) in extra {
+            fields
+                .entry(key.clone())
+                .or_insert_with(|| extra_value.clone());
+        }
+    }
+}
+
+/// Masks all but the last four characters of an API key. Keys shorter than 16
+/// characters are fully masked, so the visible suffix is never a large part of
+/// the secret.
+fn mask_api_key(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    if chars.len() < 16 {
+        return "*".repeat(chars.len());
+    }
+    let visible: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}{}", "*".repeat(chars.len() - 4), visible)
+}
+
+/// Incrementally decodes items from a streamed response body.
+pub(crate) trait StreamDecoder {
+    type Item;
+
+    /// Buffers a chunk of the body.
+    fn push(&mut self, chunk: &[u8]);
+
+    /// Returns the next complete item, if one is buffered. `eof` is set once the
+    /// body has ended, so trailing data can be flushed or dropped.
+    fn next_item(&mut self, eof: bool) -> Option<Result<Self::Item, FirecrawlError>>;
+}
+
+/// Splits an NDJSON body into lines and parses each one into `T`.
+struct NdjsonDecoder<T> {
+    buffer: Vec<u8>,
+    action: String,
+    item: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> StreamDecoder for NdjsonDecoder<T> {
+    type Item = T;
+
+    fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    fn next_item(&mut self, eof: bool) -> Option<Result<T, FirecrawlError>> {
+        loop {
+            let line = if let Some(pos) = self.buffer.iter().position(|&b| b == b'\n') {
+                self.buffer.drain(..=pos).collect::<Vec<u8>>()
+            } else if eof && !self.buffer.is_empty() {
+                std::mem::take(&mut self.buffer)
+            } else {
+                return None;
+            };
+
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+
+            return Some(parse_ndjson_line(&line, &self.action));
+        }
+    }
+}
+
+/// Parses a single NDJSON line, mapping `"success": false` lines to API errors.
+fn parse_ndjson_line<T: DeserializeOwned>(line: &[u8], action: &str) -> Result<T, FirecrawlError> {
+    let value =
+        serde_json::from_slice::<Value>(line).map_err(FirecrawlError::ResponseParseError)?;
+
+    if value.get("success").and_then(Value::as_bool) == Some(false) {
+        return Err(FirecrawlError::APIError(
+            action.to_string(),
+            serde_json::from_value(value).map_err(FirecrawlError::ResponseParseError)?,
+        ));
+    }
+
+    serde_json::from_value::<T>(value).map_err(FirecrawlError::ResponseParseError)
+}
+
+/// Injects the current OpenTelemetry context through the globally registered
+/// text map propagator, so backend spans can be linked to the caller's trace.
+/// With a `TraceContextPropagator` installed this adds the W3C
+/// `traceparent`/`tracestate` headers.
+#[cfg(feature = "otel")]
+fn inject_trace_context(headers: &mut reqwest::header::HeaderMap) {
+    let context = opentelemetry::Context::current();
+    opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut HeaderInjector(headers))
+    });
+}
+
+/// Writes propagated fields into request headers.
+#[cfg(feature = "otel")]
+struct HeaderInjector<'a>(&'a mut reqwest::header::HeaderMap);
+
+#[cfg(feature = "otel")]
+impl opentelemetry::propagation::Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        // Skip fields that are not valid headers rather than failing the request
+        if let (Ok(name), Ok(value)) = (HeaderName::from_bytes(key.as_bytes()), value.parse()) {
+            self.0.insert(name, value);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -250,6 +724,25 @@ mod tests {
         assert_eq!(client.api_url, "http://localhost:3000");
     }
 
+    #[test]
+    fn test_new_selfhosted_rejects_malformed_url() {
+        for url in ["localhost:3000", "not a url", "ftp://localhost:3000", ""] {
+            let result = Client::new_selfhosted(url, None::<&str>);
+            assert!(
+                matches!(result, Err(FirecrawlError::APIError(ref action, _)) if action == "Configuration"),
+                "expected {:?} to be rejected",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_selfhosted_accepts_valid_url() {
+        let client =
+            Client::new_selfhosted("https://firecrawl.internal:8443/", None::<&str>).unwrap();
+        assert_eq!(client.api_url, "https://firecrawl.internal:8443");
+    }
+
     #[test]
     fn test_url_builder() {
         let client = Client::new("test-key").unwrap();
@@ -270,4 +763,446 @@ mod tests {
         let client = Client::new_selfhosted("http://localhost:3000/", None::<&str>).unwrap();
         assert_eq!(client.api_url, "http://localhost:3000");
     }
+
+    #[tokio::test]
+    async fn test_unauthorized_response() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v2/crawl/job-id")
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":false,"error":"Unauthorized: Invalid token"}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("bad_key")).unwrap();
+        let result = client.get_crawl_status("job-id").await;
+
+        match result {
+            Err(FirecrawlError::Unauthorized(_, message)) => {
+                assert_eq!(message, "Unauthorized: Invalid token");
+            }
+            other => panic!("expected Unauthorized, got {:?}", other),
+        }
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_forbidden_response_without_json_body() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(403)
+            .with_body("forbidden")
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let result = client
+            .scrape("https://example.com", None::<crate::v2::ScrapeOptions>)
+            .await;
+
+        match result {
+            Err(FirecrawlError::Forbidden(_, message)) => assert_eq!(message, "Forbidden"),
+            other => panic!("expected Forbidden, got {:?}", other),
+        }
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_http_error_display() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v2/crawl/job-id")
+            .with_status(502)
+            .with_header("content-type", "text/html")
+            .with_body("<html>Bad Gateway</html>")
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("fc-secret-key")).unwrap();
+        let error = client.get_crawl_status("job-id").await.unwrap_err();
+
+        assert!(matches!(
+            error,
+            FirecrawlError::HttpRequestFailed(_, 502, _)
+        ));
+        let message = error.to_string();
+        assert_eq!(
+            message,
+            "crawl status job-id failed: HTTP error 502: Bad Gateway (/v2/crawl/job-id)"
+        );
+        assert!(!message.contains("fc-secret-key"));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_error_status_without_success_field() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v2/crawl/job-id")
+            .with_status(500)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error":"Internal server error","details":{"code":"E_WORKER"}}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let result = client.get_crawl_status("job-id").await;
+
+        match result {
+            Err(FirecrawlError::APIError(_, error)) => {
+                assert!(!error.success);
+                assert_eq!(error.error, "Internal server error");
+                assert_eq!(error.details.unwrap()["code"], "E_WORKER");
+            }
+            other => panic!("expected APIError, got {:?}", other),
+        }
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_error_status_without_success_or_message() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(500)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data":{"markdown":"stale"}}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let result = client
+            .scrape("https://example.com", None::<crate::v2::ScrapeOptions>)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(FirecrawlError::HttpRequestFailed(_, 500, _))
+        ));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/v2/crawl/job-id")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(500));
+                r#"{"success":true,"status":"completed","total":0,"completed":0,"data":[]}"#.into()
+            })
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_config(ClientConfig {
+                timeout: Some(Duration::from_millis(50)),
+                ..Default::default()
+            })
+            .unwrap();
+        let result = client.get_crawl_status("job-id").await;
+
+        assert!(
+            matches!(result, Err(FirecrawlError::Timeout(ref action)) if action.contains("job-id")),
+            "expected Timeout, got {:?}",
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_while_reading_body() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/v2/crawl/job-id")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_chunked_body(|w| {
+                // Headers and the first chunk arrive in time, then the body stalls
+                w.write_all(br#"{"success":true,"status":"#)?;
+                w.flush()?;
+                std::thread::sleep(Duration::from_millis(500));
+                w.write_all(br#""completed","total":0,"completed":0,"data":[]}"#)
+            })
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_config(ClientConfig {
+                timeout: Some(Duration::from_millis(200)),
+                ..Default::default()
+            })
+            .unwrap();
+        let result = client.get_crawl_status("job-id").await;
+
+        assert!(
+            matches!(result, Err(FirecrawlError::Timeout(_))),
+            "expected Timeout, got {:?}",
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_user_agent_header() {
+        let mut server = mockito::Server::new_async().await;
+        let default_mock = server
+            .mock("GET", "/v2/crawl/default")
+            .match_header(
+                "user-agent",
+                format!("firecrawl-rust-sdk/{}", env!("CARGO_PKG_VERSION")).as_str(),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":true,"status":"completed","total":0,"completed":0,"data":[]}"#)
+            .create_async()
+            .await;
+        let custom_mock = server
+            .mock("GET", "/v2/crawl/custom")
+            .match_header("user-agent", "my-app/2.0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":true,"status":"completed","total":0,"completed":0,"data":[]}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        client.get_crawl_status("default").await.unwrap();
+
+        let client = client
+            .with_config(ClientConfig {
+                user_agent: Some("my-app/2.0".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        client.get_crawl_status("custom").await.unwrap();
+
+        default_mock.assert_async().await;
+        custom_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_compressed_responses() {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder
+            .write_all(
+                br#"{"success":true,"status":"completed","total":1,"completed":1,"data":[]}"#,
+            )
+            .unwrap();
+        let gzipped = encoder.finish().unwrap();
+
+        let mut server = mockito::Server::new_async().await;
+        let compressed_mock = server
+            .mock("GET", "/v2/crawl/compressed")
+            .match_header(
+                "accept-encoding",
+                mockito::Matcher::Regex("gzip".to_string()),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("content-encoding", "gzip")
+            .with_body(gzipped)
+            .create_async()
+            .await;
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let status = client.get_crawl_status("compressed").await.unwrap();
+        assert_eq!(status.total, 1);
+
+        assert!(client.config().compression);
+
+        compressed_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_compression_disabled() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v2/crawl/plain")
+            .match_header("accept-encoding", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":true,"status":"completed","total":0,"completed":0,"data":[]}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_config(ClientConfig {
+                compression: false,
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(!client.config().compression);
+        client.get_crawl_status("plain").await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[cfg(feature = "otel")]
+    #[test]
+    fn test_trace_context_headers() {
+        use opentelemetry::propagation::text_map_propagator::FieldIter;
+        use opentelemetry::propagation::{Extractor, Injector, TextMapPropagator};
+        use opentelemetry::trace::{
+            SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState,
+        };
+
+        /// Minimal W3C propagator, standing in for the SDK's `TraceContextPropagator`.
+        #[derive(Debug)]
+        struct W3CPropagator;
+
+        impl TextMapPropagator for W3CPropagator {
+            fn inject_context(&self, cx: &opentelemetry::Context, injector: &mut dyn Injector) {
+                let span = cx.span();
+                let span_context = span.span_context();
+                if !span_context.is_valid() {
+                    return;
+                }
+                injector.set(
+                    "traceparent",
+                    format!(
+                        "00-{}-{}-{:02x}",
+                        span_context.trace_id(),
+                        span_context.span_id(),
+                        span_context.trace_flags().to_u8()
+                    ),
+                );
+                injector.set("tracestate", span_context.trace_state().header());
+            }
+
+            fn extract_with_context(
+                &self,
+                cx: &opentelemetry::Context,
+                _extractor: &dyn Extractor,
+            ) -> opentelemetry::Context {
+                cx.clone()
+            }
+
+            fn fields(&self) -> FieldIter<'_> {
+                FieldIter::new(&[])
+            }
+        }
+
+        let client = Client::new("test-key").unwrap();
+
+        // The default global propagator is a no-op
+        let headers = client.prepare_headers(None);
+        assert!(headers.get("traceparent").is_none());
+
+        opentelemetry::global::set_text_map_propagator(W3CPropagator);
+
+        // No active span, no headers
+        let headers = client.prepare_headers(None);
+        assert!(headers.get("traceparent").is_none());
+
+        let span_context = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::from_key_value([("vendor", "value")]).unwrap(),
+        );
+        let _guard = opentelemetry::Context::current()
+            .with_remote_span_context(span_context)
+            .attach();
+
+        let headers = client.prepare_headers(None);
+        assert_eq!(
+            headers.get("traceparent").unwrap(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+        assert_eq!(headers.get("tracestate").unwrap(), "vendor=value");
+    }
+
+    #[test]
+    fn test_config_view_masks_api_key() {
+        let client = Client::new_selfhosted("http://localhost:3000/", Some("fc-1234567890abcdef"))
+            .unwrap()
+            .with_config(ClientConfig {
+                timeout: Some(Duration::from_secs(30)),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let view = client.config();
+        assert_eq!(view.api_url, "http://localhost:3000");
+        assert_eq!(view.base_path, "/v2");
+        assert_eq!(view.api_key.as_deref(), Some("***************cdef"));
+        assert_eq!(view.timeout, Some(Duration::from_secs(30)));
+        assert_eq!(view.user_agent, DEFAULT_USER_AGENT);
+        assert!(view.compression);
+        assert!(!format!("{:?}", view).contains("fc-1234567890"));
+
+        let client = Client::new_selfhosted("http://localhost:3000", Some("short")).unwrap();
+        assert_eq!(client.config().api_key.as_deref(), Some("*****"));
+
+        // 16 characters is the shortest key with a visible suffix
+        let client =
+            Client::new_selfhosted("http://localhost:3000", Some("fc-0123456789ab")).unwrap();
+        assert_eq!(
+            client.config().api_key.as_deref(),
+            Some(&"*".repeat(15)[..])
+        );
+        let client =
+            Client::new_selfhosted("http://localhost:3000", Some("fc-0123456789abc")).unwrap();
+        assert_eq!(client.config().api_key.as_deref(), Some("************9abc"));
+
+        let client = Client::new_selfhosted("http://localhost:3000", None::<&str>).unwrap();
+        assert_eq!(client.config().api_key, None);
+    }
+
+    #[test]
+    fn test_request_id_header_rejects_invalid_name() {
+        let result = Client::new("test-key")
+            .unwrap()
+            .with_request_id_header("bad header", || "id".to_string());
+        assert!(matches!(result, Err(FirecrawlError::Missuse(_))));
+    }
+
+    #[tokio::test]
+    async fn test_request_id_header_is_unique_per_request() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut server = mockito::Server::new_async().await;
+        let counter = Arc::new(AtomicUsize::new(0));
+        let generator_counter = counter.clone();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_request_id_header("x-request-id", move || {
+                format!("req-{}", generator_counter.fetch_add(1, Ordering::SeqCst))
+            })
+            .unwrap();
+
+        let mut mocks = Vec::new();
+        for id in ["req-0", "req-1"] {
+            mocks.push(
+                server
+                    .mock("GET", "/v2/crawl/job-id")
+                    .match_header("x-request-id", id)
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(
+                        r#"{"success":true,"status":"scraping","total":0,"completed":0,"data":[]}"#,
+                    )
+                    .expect(1)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        client.get_crawl_status("job-id").await.unwrap();
+        client.get_crawl_status("job-id").await.unwrap();
+
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
 }
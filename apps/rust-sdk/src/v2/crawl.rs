@@ -1,8 +1,9 @@
 //! Crawl endpoint for Firecrawl API v2.
 
+use futures::Stream;
 use serde::{Deserialize, Serialize};
 
-use super::client::Client;
+use super::client::{json_body, Client};
 use super::scrape::ScrapeOptions;
 use super::types::{CrawlErrorsResponse, Document, JobStatus, SitemapMode, WebhookConfig};
 use crate::FirecrawlError;
@@ -51,7 +52,9 @@ pub struct CrawlOptions {
     /// Webhook configuration for job notifications.
     pub webhook: Option<WebhookConfig>,
 
-    /// Scrape options to apply to each page.
+    /// Scrape options to apply to each page. Set `change_tracking_options` and
+    /// request `Format::ChangeTracking` here to diff pages against their
+    /// previous crawl.
     pub scrape_options: Option<ScrapeOptions>,
 
     /// Enable zero data retention mode.
@@ -69,6 +72,23 @@ pub struct CrawlOptions {
     pub poll_interval: Option<u64>,
 }
 
+impl CrawlOptions {
+    /// Rejects options the API would refuse, to avoid a round-trip.
+    fn validate(&self) -> Result<(), FirecrawlError> {
+        if self.max_discovery_depth == Some(0) {
+            return Err(FirecrawlError::Missuse(
+                "max_discovery_depth must be greater than 0".to_string(),
+            ));
+        }
+        if self.limit == Some(0) {
+            return Err(FirecrawlError::Missuse(
+                "limit must be greater than 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 /// Request body for crawl endpoint.
 #[derive(Deserialize, Serialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
@@ -133,6 +153,11 @@ impl Client {
     ///
     /// A `CrawlResponse` containing the job ID.
     ///
+    /// # Errors
+    ///
+    /// Returns `FirecrawlError::Missuse` without sending the request if
+    /// `max_discovery_depth` or `limit` is zero.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -158,6 +183,8 @@ impl Client {
         options: impl Into<Option<CrawlOptions>>,
     ) -> Result<CrawlResponse, FirecrawlError> {
         let options = options.into().unwrap_or_default();
+        options.validate()?;
+
         let body = CrawlRequest {
             url: url.as_ref().to_string(),
             options: options.clone(),
@@ -169,11 +196,11 @@ impl Client {
             .client
             .post(self.url("/crawl"))
             .headers(headers)
-            .json(&body)
+            .json(&json_body(&body, None)?)
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Starting crawl of {:?}", url.as_ref()), e)
+                FirecrawlError::from_reqwest(format!("Starting crawl of {:?}", url.as_ref()), e)
             })?;
 
         self.handle_response(response, "start crawl").await
@@ -216,7 +243,7 @@ impl Client {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Checking crawl status {}", id.as_ref()), e)
+                FirecrawlError::from_reqwest(format!("Checking crawl status {}", id.as_ref()), e)
             })?;
 
         let mut status: CrawlJob = self
@@ -235,6 +262,83 @@ impl Client {
         Ok(status)
     }
 
+    /// Streams the documents of a crawl job as line-delimited JSON.
+    ///
+    /// Unlike [`Client::get_crawl_status`], the results are not buffered: each
+    /// document is yielded as soon as its line has been received, which keeps
+    /// memory bounded for large crawls. Requires a server that supports
+    /// NDJSON output for crawl results.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The crawl job ID.
+    ///
+    /// # Returns
+    ///
+    /// A stream of `Document`s. Malformed lines are yielded as errors without
+    /// ending the stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FirecrawlError::UnexpectedContentType` if the server answers
+    /// with anything other than NDJSON.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::Client;
+    /// use futures::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("your-api-key")?;
+    ///
+    ///     let mut documents = Box::pin(client.stream_crawl_documents("job-id").await?);
+    ///     while let Some(document) = documents.next().await {
+    ///         println!("Markdown: {:?}", document?.markdown);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn stream_crawl_documents(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<impl Stream<Item = Result<Document, FirecrawlError>>, FirecrawlError> {
+        let response = self
+            .client
+            .get(self.url(&format!("/crawl/{}", id.as_ref())))
+            .headers(self.prepare_headers(None))
+            .header("Accept", "application/x-ndjson")
+            .send()
+            .await
+            .map_err(|e| {
+                FirecrawlError::from_reqwest(format!("Streaming crawl results {}", id.as_ref()), e)
+            })?;
+
+        let action = format!("crawl stream {}", id.as_ref());
+
+        // Servers without NDJSON support answer with a buffered JSON job instead
+        if response.status().is_success() {
+            let content_type = response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or_default();
+            let essence = content_type.split(';').next().unwrap_or_default().trim();
+            if !essence.eq_ignore_ascii_case("application/x-ndjson")
+                && !essence.eq_ignore_ascii_case("application/ndjson")
+            {
+                return Err(FirecrawlError::UnexpectedContentType(
+                    action,
+                    content_type.to_string(),
+                ));
+            }
+        }
+
+        self.handle_ndjson_stream(response, action).await
+    }
+
     /// Fetches the next page of crawl results.
     async fn get_crawl_status_next(&self, next: &str) -> Result<CrawlJob, FirecrawlError> {
         let response = self
@@ -243,7 +347,9 @@ impl Client {
             .headers(self.prepare_headers(None))
             .send()
             .await
-            .map_err(|e| FirecrawlError::HttpError(format!("Paginating crawl at {}", next), e))?;
+            .map_err(|e| {
+                FirecrawlError::from_reqwest(format!("Paginating crawl at {}", next), e)
+            })?;
 
         self.handle_response(response, "crawl pagination").await
     }
@@ -326,7 +432,7 @@ impl Client {
                             data: status
                                 .data
                                 .into_iter()
-                                .map(|d| convert_v2_document_to_v1(d))
+                                .map(convert_v2_document_to_v1)
                                 .collect(),
                         },
                     ));
@@ -344,7 +450,7 @@ impl Client {
                             data: status
                                 .data
                                 .into_iter()
-                                .map(|d| convert_v2_document_to_v1(d))
+                                .map(convert_v2_document_to_v1)
                                 .collect(),
                         },
                     ));
@@ -389,7 +495,7 @@ impl Client {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Cancelling crawl {}", id.as_ref()), e)
+                FirecrawlError::from_reqwest(format!("Cancelling crawl {}", id.as_ref()), e)
             })?;
 
         self.handle_response(response, "cancel crawl").await
@@ -433,7 +539,7 @@ impl Client {
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Getting crawl errors {}", id.as_ref()), e)
+                FirecrawlError::from_reqwest(format!("Getting crawl errors {}", id.as_ref()), e)
             })?;
 
         self.handle_response(response, "crawl errors").await
@@ -454,7 +560,8 @@ fn convert_v2_document_to_v1(doc: Document) -> crate::document::Document {
         raw_html: doc.raw_html,
         screenshot: doc.screenshot,
         links: doc.links,
-        extract: doc.json,
+        extract: doc.json.clone(),
+        json: doc.json,
         metadata: crate::document::DocumentMetadata {
             source_url: metadata.source_url.unwrap_or_default(),
             status_code: metadata.status_code.unwrap_or(0),
@@ -488,6 +595,7 @@ fn convert_v2_document_to_v1(doc: Document) -> crate::document::Document {
             dc_date: metadata.dc_date,
             dc_date_created: metadata.dc_date_created,
             dcterms_created: metadata.dcterms_created,
+            extra: metadata.extra,
         },
         warning: doc.warning,
     }
@@ -527,6 +635,180 @@ mod tests {
         mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_start_crawl_with_path_filters() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/crawl")
+            .match_body(mockito::Matcher::Json(json!({
+                "url": "https://example.com",
+                "includePaths": ["^/blog/.*"],
+                "excludePaths": ["^/blog/drafts/.*", "^/admin"]
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "id": "crawl-123",
+                    "url": "https://api.firecrawl.dev/v2/crawl/crawl-123"
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = CrawlOptions {
+            include_paths: Some(vec!["^/blog/.*".to_string()]),
+            exclude_paths: Some(vec!["^/blog/drafts/.*".to_string(), "^/admin".to_string()]),
+            ..Default::default()
+        };
+
+        let response = client
+            .start_crawl("https://example.com", options)
+            .await
+            .unwrap();
+
+        assert_eq!(response.id, "crawl-123");
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_start_crawl_with_scrape_options() {
+        use crate::v2::{Action, Format};
+
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/crawl")
+            .match_body(mockito::Matcher::Json(json!({
+                "url": "https://example.com",
+                "limit": 10,
+                "scrapeOptions": {
+                    "formats": ["markdown", "links"],
+                    "onlyMainContent": true,
+                    "actions": [{ "type": "wait", "milliseconds": 500 }]
+                }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "id": "crawl-123",
+                    "url": "https://api.firecrawl.dev/v2/crawl/crawl-123"
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = CrawlOptions {
+            limit: Some(10),
+            scrape_options: Some(ScrapeOptions {
+                formats: Some(vec![Format::Markdown, Format::Links]),
+                only_main_content: Some(true),
+                actions: Some(vec![Action::Wait {
+                    milliseconds: Some(500),
+                    selector: None,
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let response = client
+            .start_crawl("https://example.com", options)
+            .await
+            .unwrap();
+
+        assert_eq!(response.id, "crawl-123");
+        mock.assert();
+    }
+
+    #[test]
+    fn test_link_scope_serialization() {
+        let options = CrawlOptions {
+            allow_external_links: Some(true),
+            allow_subdomains: Some(false),
+            ..Default::default()
+        };
+        let body = serde_json::to_value(&options).unwrap();
+        assert_eq!(body["allowExternalLinks"], true);
+        assert_eq!(body["allowSubdomains"], false);
+
+        let body = serde_json::to_value(CrawlOptions::default()).unwrap();
+        assert!(body.get("allowExternalLinks").is_none());
+        assert!(body.get("allowSubdomains").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_start_crawl_with_depth_and_limit() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/crawl")
+            .match_body(mockito::Matcher::Json(json!({
+                "url": "https://example.com",
+                "maxDiscoveryDepth": 3,
+                "limit": 50
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "id": "crawl-123",
+                    "url": "https://api.firecrawl.dev/v2/crawl/crawl-123"
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = CrawlOptions {
+            max_discovery_depth: Some(3),
+            limit: Some(50),
+            ..Default::default()
+        };
+
+        let response = client
+            .start_crawl("https://example.com", options)
+            .await
+            .unwrap();
+
+        assert_eq!(response.id, "crawl-123");
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_start_crawl_rejects_zero_depth_and_limit() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server.mock("POST", "/v2/crawl").expect(0).create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+
+        let options = CrawlOptions {
+            max_discovery_depth: Some(0),
+            ..Default::default()
+        };
+        let result = client.start_crawl("https://example.com", options).await;
+        assert!(
+            matches!(result, Err(FirecrawlError::Missuse(m)) if m.contains("max_discovery_depth"))
+        );
+
+        let options = CrawlOptions {
+            limit: Some(0),
+            ..Default::default()
+        };
+        let result = client.crawl("https://example.com", options).await;
+        assert!(matches!(result, Err(FirecrawlError::Missuse(m)) if m.contains("limit")));
+
+        mock.assert();
+    }
+
     #[tokio::test]
     async fn test_get_crawl_status_with_mock() {
         let mut server = mockito::Server::new_async().await;
@@ -573,6 +855,94 @@ mod tests {
         mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_crawl_with_change_tracking() {
+        use crate::v2::{
+            ChangeStatus, ChangeTrackingMode, ChangeTrackingOptions, Format, PageVisibility,
+        };
+
+        let mut server = mockito::Server::new_async().await;
+
+        let start_mock = server
+            .mock("POST", "/v2/crawl")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "scrapeOptions": {
+                    "formats": ["markdown", "changeTracking"],
+                    "changeTrackingOptions": { "modes": ["json"], "tag": "weekly" }
+                }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "id": "crawl-123",
+                    "url": "https://api.firecrawl.dev/v2/crawl/crawl-123"
+                })
+                .to_string(),
+            )
+            .create();
+
+        let status_mock = server
+            .mock("GET", "/v2/crawl/crawl-123")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "completed",
+                    "total": 2,
+                    "completed": 2,
+                    "data": [
+                        {
+                            "markdown": "# Page 1",
+                            "changeTracking": {
+                                "previousScrapeAt": "2025-01-01T00:00:00Z",
+                                "changeStatus": "changed",
+                                "json": { "price": { "previous": 10, "current": 12 } }
+                            }
+                        },
+                        {
+                            "markdown": "# Page 2",
+                            "changeTracking": { "changeStatus": "archived", "visibility": "stale" }
+                        }
+                    ]
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = CrawlOptions {
+            scrape_options: Some(ScrapeOptions {
+                formats: Some(vec![Format::Markdown, Format::ChangeTracking]),
+                change_tracking_options: Some(ChangeTrackingOptions {
+                    modes: Some(vec![ChangeTrackingMode::Json]),
+                    tag: Some("weekly".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let response = client
+            .start_crawl("https://example.com", options)
+            .await
+            .unwrap();
+        let status = client.get_crawl_status(&response.id).await.unwrap();
+
+        let first = status.data[0].parsed_change_tracking().unwrap();
+        assert_eq!(first.change_status, Some(ChangeStatus::Changed));
+        assert_eq!(first.json.unwrap()["price"]["current"], 12);
+
+        // Values newer than this SDK still parse
+        let second = status.data[1].parsed_change_tracking().unwrap();
+        assert_eq!(second.change_status, Some(ChangeStatus::Unknown));
+        assert_eq!(second.visibility, Some(PageVisibility::Unknown));
+
+        start_mock.assert();
+        status_mock.assert();
+    }
+
     #[tokio::test]
     async fn test_cancel_crawl_with_mock() {
         let mut server = mockito::Server::new_async().await;
@@ -631,6 +1001,118 @@ mod tests {
         mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_stream_crawl_documents_with_mock() {
+        use futures::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+
+        // Lines are split across chunks and the last one has no trailing newline
+        let mock = server
+            .mock("GET", "/v2/crawl/crawl-123")
+            .match_header("accept", "application/x-ndjson")
+            .with_status(200)
+            .with_header("content-type", "application/x-ndjson")
+            .with_chunked_body(|w| {
+                w.write_all(b"{\"markdown\": \"# Page 1\"}\n{\"mark")?;
+                w.write_all(b"down\": \"# Page 2\"}\n\n")?;
+                w.write_all(b"{\"markdown\": \"# Page 3\"}")
+            })
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let documents: Vec<_> = client
+            .stream_crawl_documents("crawl-123")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert_eq!(documents.len(), 3);
+        let markdown: Vec<_> = documents
+            .into_iter()
+            .map(|d| d.unwrap().markdown.unwrap())
+            .collect();
+        assert_eq!(markdown, vec!["# Page 1", "# Page 2", "# Page 3"]);
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_stream_crawl_documents_partial_line() {
+        use futures::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/v2/crawl/crawl-123")
+            .with_status(200)
+            .with_header("content-type", "application/x-ndjson")
+            .with_body("{\"markdown\": \"# Page 1\"}\n{\"markdown\": \"# Pa")
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let documents: Vec<_> = client
+            .stream_crawl_documents("crawl-123")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert_eq!(documents.len(), 2);
+        assert!(documents[0].is_ok());
+        assert!(matches!(
+            documents[1],
+            Err(FirecrawlError::ResponseParseError(_))
+        ));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_stream_crawl_documents_requires_ndjson() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/v2/crawl/crawl-123")
+            .with_status(200)
+            .with_header("content-type", "application/json; charset=utf-8")
+            .with_body(json!({ "success": true, "status": "completed", "data": [] }).to_string())
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let result = client.stream_crawl_documents("crawl-123").await;
+
+        assert!(matches!(
+            result,
+            Err(FirecrawlError::UnexpectedContentType(_, ref content_type))
+                if content_type == "application/json; charset=utf-8"
+        ));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_stream_crawl_documents_error_response() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/v2/crawl/invalid-id")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": false,
+                    "error": "Crawl job not found"
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let result = client.stream_crawl_documents("invalid-id").await;
+
+        assert!(matches!(result, Err(FirecrawlError::APIError(_, _))));
+        mock.assert();
+    }
+
     #[tokio::test]
     async fn test_crawl_with_options() {
         let mut server = mockito::Server::new_async().await;
@@ -0,0 +1,273 @@
+//! Server-sent crawl progress events for Firecrawl API v2.
+
+use futures::stream::Stream;
+use serde::Deserialize;
+
+use super::client::{Client, StreamDecoder};
+use super::types::{Document, JobStatus};
+use crate::FirecrawlError;
+
+/// A progress event emitted while a crawl is running.
+#[derive(Debug, Clone)]
+pub enum CrawlEvent {
+    /// A page was scraped (`document` event).
+    Document(Box<Document>),
+    /// The crawl finished (`done` event).
+    Completed {
+        /// Final status of the crawl job.
+        status: JobStatus,
+        /// Total number of pages crawled.
+        total: Option<u32>,
+        /// Number of pages completed.
+        completed: Option<u32>,
+    },
+    /// The crawl reported an error (`error` event).
+    Error(String),
+}
+
+/// Payload of a `done` event.
+#[derive(Deserialize)]
+struct DoneEvent {
+    status: JobStatus,
+    total: Option<u32>,
+    completed: Option<u32>,
+}
+
+/// Payload of an `error` event.
+#[derive(Deserialize)]
+struct ErrorEvent {
+    error: String,
+}
+
+impl Client {
+    /// Subscribes to live progress events for a crawl job.
+    ///
+    /// Events are parsed from a `text/event-stream` response as they arrive, which
+    /// avoids polling the crawl status for live UIs. Events with an unknown name
+    /// are skipped, and the stream ends when the server closes the connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The crawl job ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the server responds with a
+    /// non-success status. Malformed events are yielded as error items.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::{Client, CrawlEvent};
+    /// use futures::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("your-api-key")?;
+    ///
+    ///     let mut events = Box::pin(client.crawl_events("job-id").await?);
+    ///     while let Some(event) = events.next().await {
+    ///         match event? {
+    ///             CrawlEvent::Document(doc) => println!("Scraped: {:?}", doc.metadata),
+    ///             CrawlEvent::Completed { status, .. } => println!("Done: {:?}", status),
+    ///             CrawlEvent::Error(error) => eprintln!("Error: {}", error),
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn crawl_events(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<impl Stream<Item = Result<CrawlEvent, FirecrawlError>>, FirecrawlError> {
+        let action = format!("crawl events {}", id.as_ref());
+
+        let response = self
+            .client
+            .get(self.url(&format!("/crawl/{}", id.as_ref())))
+            .headers(self.prepare_headers(None))
+            .header("Accept", "text/event-stream")
+            .send()
+            .await
+            .map_err(|e| {
+                FirecrawlError::from_reqwest(format!("Subscribing to crawl {}", id.as_ref()), e)
+            })?;
+
+        self.handle_stream(response, action, SseParser::default())
+            .await
+    }
+}
+
+/// Maps a raw SSE event to a `CrawlEvent`, returning `None` for unknown events.
+fn parse_crawl_event(event: &str, data: &str) -> Option<Result<CrawlEvent, FirecrawlError>> {
+    let item = match event {
+        "document" => {
+            serde_json::from_str::<Document>(data).map(|doc| CrawlEvent::Document(Box::new(doc)))
+        }
+        "done" => serde_json::from_str::<DoneEvent>(data).map(|done| CrawlEvent::Completed {
+            status: done.status,
+            total: done.total,
+            completed: done.completed,
+        }),
+        "error" => serde_json::from_str::<ErrorEvent>(data).map(|e| CrawlEvent::Error(e.error)),
+        _ => return None,
+    };
+
+    Some(item.map_err(FirecrawlError::ResponseParseError))
+}
+
+/// Incremental parser for `text/event-stream` bodies.
+#[derive(Default)]
+struct SseParser {
+    buffer: Vec<u8>,
+    event: String,
+    data: Vec<String>,
+}
+
+impl SseParser {
+    /// Returns the next complete `(event, data)` pair, if one is buffered.
+    fn next_event(&mut self) -> Option<(String, String)> {
+        while let Some(pos) = self.buffer.iter().position(|&b| b == b'\n') {
+            let line = self.buffer.drain(..=pos).collect::<Vec<u8>>();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            if line.is_empty() {
+                // A blank line dispatches the event; events without data are ignored
+                let event = std::mem::take(&mut self.event);
+                let data = std::mem::take(&mut self.data);
+                if !data.is_empty() {
+                    let event = if event.is_empty() {
+                        "message".to_string()
+                    } else {
+                        event
+                    };
+                    return Some((event, data.join("\n")));
+                }
+                continue;
+            }
+
+            if line.starts_with(':') {
+                continue;
+            }
+
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match field {
+                "event" => self.event = value.to_string(),
+                "data" => self.data.push(value.to_string()),
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
+
+impl StreamDecoder for SseParser {
+    type Item = CrawlEvent;
+
+    fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    // A trailing event without its blank line is incomplete and dropped
+    fn next_item(&mut self, _eof: bool) -> Option<Result<CrawlEvent, FirecrawlError>> {
+        while let Some((event, data)) = self.next_event() {
+            if let Some(item) = parse_crawl_event(&event, &data) {
+                return Some(item);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn test_crawl_events() {
+        let mut server = mockito::Server::new_async().await;
+
+        let body = concat!(
+            ": keep-alive\n\n",
+            "event: document\n",
+            "data: {\"markdown\": \"# Page 1\", \"metadata\": {\"sourceURL\": \"https://example.com/1\"}}\n\n",
+            "event: progress\n",
+            "data: {\"completed\": 1}\n\n",
+            "event: document\r\n",
+            "data: {\"markdown\":\r\n",
+            "data: \"# Page 2\"}\r\n\r\n",
+            "event: error\n",
+            "data: {\"error\": \"Timed out on https://example.com/3\"}\n\n",
+            "event: done\n",
+            "data: {\"status\": \"completed\", \"total\": 3, \"completed\": 2}\n\n",
+        );
+
+        let mock = server
+            .mock("GET", "/v2/crawl/job-id")
+            .match_header("accept", "text/event-stream")
+            .with_status(200)
+            .with_header("content-type", "text/event-stream")
+            .with_chunked_body(move |w| {
+                // Split mid-event to exercise buffering across chunks
+                let (first, second) = body.split_at(60);
+                w.write_all(first.as_bytes())?;
+                w.write_all(second.as_bytes())
+            })
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let events: Vec<_> = client
+            .crawl_events("job-id")
+            .await
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(events.len(), 4);
+        assert!(
+            matches!(&events[0], CrawlEvent::Document(doc) if doc.markdown.as_deref() == Some("# Page 1"))
+        );
+        assert!(
+            matches!(&events[1], CrawlEvent::Document(doc) if doc.markdown.as_deref() == Some("# Page 2"))
+        );
+        assert!(matches!(&events[2], CrawlEvent::Error(e) if e.contains("example.com/3")));
+        assert!(matches!(
+            events[3],
+            CrawlEvent::Completed {
+                status: JobStatus::Completed,
+                total: Some(3),
+                completed: Some(2),
+            }
+        ));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_crawl_events_error_status() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/v2/crawl/missing")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success": false, "error": "Job not found"}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let result = client.crawl_events("missing").await;
+
+        assert!(matches!(result, Err(FirecrawlError::APIError(_, _))));
+        mock.assert_async().await;
+    }
+}
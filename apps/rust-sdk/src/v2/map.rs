@@ -1,9 +1,9 @@
 //! Map endpoint for Firecrawl API v2.
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-use super::client::Client;
-use super::types::{LocationConfig, SearchResultWeb, SitemapMode};
+use super::client::{json_body, Client};
+use super::types::{LinkSet, LocationConfig, SitemapMode};
 use crate::FirecrawlError;
 
 /// Options for mapping a URL.
@@ -51,12 +51,62 @@ struct MapRequest {
 pub struct MapResponse {
     /// Whether the request was successful.
     pub success: bool,
-    /// Discovered links with metadata.
-    pub links: Vec<SearchResultWeb>,
+    /// Discovered links with metadata. Links the server returns as plain URL
+    /// strings have all metadata fields set to `None`.
+    #[serde(deserialize_with = "deserialize_links")]
+    pub links: Vec<MapLink>,
     /// Warning message if any.
     pub warning: Option<String>,
 }
 
+impl MapResponse {
+    /// Splits the discovered links into internal and external ones relative to
+    /// `base_url`, typically the URL that was mapped.
+    pub fn link_set(&self, base_url: &str) -> LinkSet {
+        LinkSet::classify(base_url, self.links.iter().map(|link| &link.url))
+    }
+}
+
+/// A link discovered by the map endpoint.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MapLink {
+    /// The discovered URL.
+    pub url: String,
+    /// Page title, if known.
+    pub title: Option<String>,
+    /// Page description, if known.
+    pub description: Option<String>,
+    /// Last modification date from the sitemap (`lastmod`), if present.
+    #[serde(alias = "lastmod")]
+    pub last_modified: Option<String>,
+}
+
+/// Deserializes map links, accepting plain URL strings alongside link objects.
+fn deserialize_links<'de, D>(deserializer: D) -> Result<Vec<MapLink>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Link {
+        Url(String),
+        Link(MapLink),
+    }
+
+    Ok(Vec::<Link>::deserialize(deserializer)?
+        .into_iter()
+        .map(|link| match link {
+            Link::Url(url) => MapLink {
+                url,
+                ..Default::default()
+            },
+            Link::Link(link) => link,
+        })
+        .collect())
+}
+
 impl Client {
     /// Maps a URL to discover all associated links.
     ///
@@ -117,10 +167,10 @@ impl Client {
             .client
             .post(self.url("/map"))
             .headers(headers)
-            .json(&body)
+            .json(&json_body(&body, None)?)
             .send()
             .await
-            .map_err(|e| FirecrawlError::HttpError(format!("Mapping {:?}", url.as_ref()), e))?;
+            .map_err(|e| FirecrawlError::from_reqwest(format!("Mapping {:?}", url.as_ref()), e))?;
 
         self.handle_response(response, "map").await
     }
@@ -210,6 +260,96 @@ mod tests {
         mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_map_plain_and_enriched_links() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/map")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "links": [
+                        "https://example.com/plain",
+                        {
+                            "url": "https://example.com/blog",
+                            "title": "Blog",
+                            "lastModified": "2024-05-01"
+                        },
+                        { "url": "https://example.com/feed", "lastmod": "2024-06-01T10:00:00Z" }
+                    ]
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let response = client.map("https://example.com", None).await.unwrap();
+
+        let links: Vec<_> = response
+            .links
+            .iter()
+            .map(|link| {
+                (
+                    link.url.as_str(),
+                    link.title.as_deref(),
+                    link.last_modified.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            links,
+            vec![
+                ("https://example.com/plain", None, None),
+                ("https://example.com/blog", Some("Blog"), Some("2024-05-01")),
+                (
+                    "https://example.com/feed",
+                    None,
+                    Some("2024-06-01T10:00:00Z")
+                ),
+            ]
+        );
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_map_link_set() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/map")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "links": [
+                        { "url": "https://example.com/about" },
+                        { "url": "https://blog.example.com/post" },
+                        { "url": "https://www.example.com/contact" }
+                    ]
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let response = client.map("https://example.com", None).await.unwrap();
+        let link_set = response.link_set("https://example.com");
+
+        assert_eq!(
+            link_set.internal,
+            vec![
+                "https://example.com/about",
+                "https://www.example.com/contact"
+            ]
+        );
+        assert_eq!(link_set.external, vec!["https://blog.example.com/post"]);
+        mock.assert();
+    }
+
     #[tokio::test]
     async fn test_map_with_options() {
         let mut server = mockito::Server::new_async().await;
@@ -23,8 +23,11 @@
 
 mod agent;
 mod batch_scrape;
+mod cache;
 mod client;
 mod crawl;
+#[cfg(feature = "sse")]
+mod crawl_events;
 mod map;
 mod scrape;
 mod search;
@@ -32,8 +35,11 @@ mod types;
 
 pub use agent::*;
 pub use batch_scrape::*;
-pub use client::Client;
+pub use cache::*;
+pub use client::{Client, ClientConfig, ClientConfigView};
 pub use crawl::*;
+#[cfg(feature = "sse")]
+pub use crawl_events::*;
 pub use map::*;
 pub use scrape::*;
 pub use search::*;
@@ -1,10 +1,14 @@
 //! Scrape endpoint for Firecrawl API v2.
 
+use base64::Engine;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
-use super::client::Client;
+use super::cache::scrape_cache_key;
+use super::client::{json_body, merge_extra, Client};
 use super::types::{
     Action, AttributeSelector, ChangeTrackingOptions, Document, Format, JsonOptions,
     LocationConfig, ProxyType, ScreenshotOptions,
@@ -14,12 +18,13 @@ use crate::FirecrawlError;
 /// Options for scraping a URL.
 #[serde_with::skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, Clone)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", remote = "Self")]
 pub struct ScrapeOptions {
     /// Output formats to include in the response.
     pub formats: Option<Vec<Format>>,
 
-    /// Additional HTTP headers to send with the request.
+    /// Additional HTTP headers to use when loading the page (e.g. cookies or a
+    /// custom user agent). These are not sent to the Firecrawl API itself.
     pub headers: Option<HashMap<String, String>>,
 
     /// HTML tags to exclusively include in the output.
@@ -64,15 +69,20 @@ pub struct ScrapeOptions {
     /// Proxy type to use.
     pub proxy: Option<ProxyType>,
 
-    /// Maximum age of cached content to accept (seconds).
+    /// Accept a cached result up to this many milliseconds old instead of
+    /// scraping the page again. Trades freshness for much lower latency.
     pub max_age: Option<u32>,
 
-    /// Minimum age of cached content to accept (seconds).
+    /// Only accept a cached result at least this many milliseconds old.
     pub min_age: Option<u32>,
 
     /// Store the result in cache for future requests.
     pub store_in_cache: Option<bool>,
 
+    /// Enable zero data retention: Firecrawl does not keep the scraped content
+    /// after returning it. May need to be enabled for your team.
+    pub zero_data_retention: Option<bool>,
+
     /// Integration identifier for tracking.
     pub integration: Option<String>,
 
@@ -87,6 +97,29 @@ pub struct ScrapeOptions {
 
     /// Attribute selectors for extraction.
     pub attribute_selectors: Option<Vec<AttributeSelector>>,
+
+    /// Additional fields merged into the request body, for API features the
+    /// SDK does not model yet. Typed fields take precedence over fields of the
+    /// same name here.
+    #[serde(flatten, skip_serializing)]
+    pub extra: Option<Map<String, Value>>,
+}
+
+// `remote = "Self"` turns the derived impls into inherent functions, so these
+// impls can merge `extra` after the typed fields.
+impl Serialize for ScrapeOptions {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut value = ScrapeOptions::serialize(self, serde_json::value::Serializer)
+            .map_err(serde::ser::Error::custom)?;
+        merge_extra(&mut value, self.extra.as_ref());
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScrapeOptions {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ScrapeOptions::deserialize(deserializer)
+    }
 }
 
 /// Parser configuration for document parsing.
@@ -123,9 +156,22 @@ struct ScrapeResponse {
     warning: Option<String>,
 }
 
+/// An artifact written by [`Client::scrape_to_dir`].
+#[derive(Debug)]
+pub struct SavedArtifact {
+    /// Where the artifact was (or would have been) saved.
+    pub path: PathBuf,
+    /// Whether downloading, decoding and writing the artifact succeeded.
+    pub result: Result<(), FirecrawlError>,
+}
+
 impl Client {
     /// Scrapes a URL and returns the content in the requested formats.
     ///
+    /// Setting `ScrapeOptions::max_age` lets Firecrawl answer from its cache
+    /// when it has a recent enough copy of the page, which is much faster than
+    /// a fresh scrape but may return content up to `max_age` milliseconds old.
+    ///
     /// # Arguments
     ///
     /// * `url` - The URL to scrape.
@@ -165,9 +211,46 @@ impl Client {
         url: impl AsRef<str>,
         options: impl Into<Option<ScrapeOptions>>,
     ) -> Result<Document, FirecrawlError> {
+        let options = options.into().unwrap_or_default();
+
+        // Zero data retention scrapes must not be kept, even in process
+        let cache = match options.zero_data_retention {
+            Some(true) => None,
+            _ => self.config.scrape_cache.as_ref(),
+        };
+
+        let cache_key = match cache {
+            Some(cache) => {
+                let options = json_body(&options, None)?.to_string();
+                let key = scrape_cache_key(url.as_ref(), &options);
+                if let Some(document) = cache.get(&key) {
+                    return Ok(document);
+                }
+                Some(key)
+            }
+            None => None,
+        };
+
+        let document = self.post_scrape(url.as_ref(), options).await?;
+
+        if let (Some(cache), Some(key)) = (cache, cache_key) {
+            cache.insert(key, document.clone());
+        }
+
+        Ok(document)
+    }
+
+    /// Sends a scrape request, bypassing the scrape cache.
+    async fn post_scrape(
+        &self,
+        url: &str,
+        mut options: ScrapeOptions,
+    ) -> Result<Document, FirecrawlError> {
+        // Extra fields go on the top level, below `url`
+        let extra = options.extra.take();
         let body = ScrapeRequest {
-            url: url.as_ref().to_string(),
-            options: options.into().unwrap_or_default(),
+            url: url.to_string(),
+            options,
         };
 
         let headers = self.prepare_headers(None);
@@ -176,16 +259,99 @@ impl Client {
             .client
             .post(self.url("/scrape"))
             .headers(headers)
-            .json(&body)
+            .json(&json_body(&body, extra.as_ref())?)
             .send()
             .await
-            .map_err(|e| FirecrawlError::HttpError(format!("Scraping {:?}", url.as_ref()), e))?;
+            .map_err(|e| FirecrawlError::from_reqwest(format!("Scraping {:?}", url), e))?;
 
         let response: ScrapeResponse = self.handle_response(response, "scrape").await?;
+        self.check_warnings(
+            "scrape",
+            response
+                .warning
+                .iter()
+                .chain(&response.data.warning)
+                .cloned(),
+        )?;
 
         Ok(response.data)
     }
 
+    /// Scrapes a URL and reports how long the request took.
+    ///
+    /// Behaves like [`Client::scrape`], additionally returning the time spent
+    /// sending the request and handling the response. The request always goes
+    /// to the API, bypassing `ClientConfig::scrape_cache`, so the duration is
+    /// never that of a cache hit.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("your-api-key")?;
+    ///
+    ///     let (document, elapsed) = client.scrape_timed("https://example.com", None).await?;
+    ///     println!("Scraped {:?} in {:?}", document.metadata, elapsed);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn scrape_timed(
+        &self,
+        url: impl AsRef<str>,
+        options: impl Into<Option<ScrapeOptions>>,
+    ) -> Result<(Document, Duration), FirecrawlError> {
+        let start = Instant::now();
+        let document = self
+            .post_scrape(url.as_ref(), options.into().unwrap_or_default())
+            .await?;
+        Ok((document, start.elapsed()))
+    }
+
+    /// Scrapes a URL and returns only its markdown content.
+    ///
+    /// This is a convenience method for the common case of wanting the page
+    /// as markdown, without unwrapping the full `Document`.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL to scrape.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scrape fails, or `FirecrawlError::MissingFormat` if
+    /// the response contains no markdown.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("your-api-key")?;
+    ///
+    ///     let markdown = client.scrape_markdown("https://example.com").await?;
+    ///     println!("{}", markdown);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn scrape_markdown(&self, url: impl AsRef<str>) -> Result<String, FirecrawlError> {
+        let options = ScrapeOptions {
+            formats: Some(vec![Format::Markdown]),
+            ..Default::default()
+        };
+
+        let document = self.scrape(url.as_ref(), options).await?;
+        document
+            .markdown
+            .ok_or_else(|| FirecrawlError::MissingFormat("markdown".to_string()))
+    }
+
     /// Scrapes a URL with a JSON schema for structured extraction.
     ///
     /// This is a convenience method that combines scraping with JSON extraction.
@@ -248,12 +414,274 @@ impl Client {
         let document = self.scrape(url, options).await?;
         Ok(document.json.unwrap_or(Value::Null))
     }
+
+    /// Scrapes a URL after performing a sequence of browser actions.
+    ///
+    /// This is a convenience method for interacting with JS-heavy pages
+    /// (clicking, typing, scrolling) before the content is captured.
+    /// Actions are performed in the order provided.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL to scrape.
+    /// * `actions` - Browser actions to perform before scraping.
+    /// * `options` - Optional scrape configuration. Any `actions` set here are replaced.
+    ///
+    /// # Returns
+    ///
+    /// A `Document` containing the scraped content and any action results.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::{Action, Client, ScrollDirection};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("your-api-key")?;
+    ///
+    ///     let actions = vec![
+    ///         Action::Click { selector: "#load-more".to_string() },
+    ///         Action::Wait { milliseconds: Some(1000), selector: None },
+    ///         Action::Scroll { direction: ScrollDirection::Down, selector: None },
+    ///     ];
+    ///
+    ///     let document = client
+    ///         .scrape_with_actions("https://example.com", actions, None)
+    ///         .await?;
+    ///     println!("Markdown: {:?}", document.markdown);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn scrape_with_actions(
+        &self,
+        url: impl AsRef<str>,
+        actions: Vec<Action>,
+        options: impl Into<Option<ScrapeOptions>>,
+    ) -> Result<Document, FirecrawlError> {
+        let options = ScrapeOptions {
+            actions: Some(actions),
+            ..options.into().unwrap_or_default()
+        };
+
+        self.scrape(url, options).await
+    }
+
+    /// Scrapes a URL and saves its binary artifacts to a directory.
+    ///
+    /// Saves the page screenshot as `screenshot.<ext>`, and screenshots and PDFs
+    /// produced by `actions` as `screenshot-<n>.<ext>` and `pdf-<n>.pdf`. Artifacts
+    /// returned as base64 data are decoded; URLs are downloaded without sending
+    /// the API key. The directory is created if needed and existing files are
+    /// overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL to scrape.
+    /// * `options` - Optional scrape configuration. Request `Format::Screenshot`
+    ///   or screenshot/PDF actions to produce artifacts.
+    /// * `dir` - The directory to save artifacts to.
+    ///
+    /// # Returns
+    ///
+    /// The scraped document and one entry per artifact, so a failed download or
+    /// write never discards the document or the other artifacts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if the scrape itself fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::{Client, Format, ScrapeOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("your-api-key")?;
+    ///
+    ///     let options = ScrapeOptions {
+    ///         formats: Some(vec![Format::Markdown, Format::Screenshot]),
+    ///         ..Default::default()
+    ///     };
+    ///     let (document, artifacts) = client
+    ///         .scrape_to_dir("https://example.com", options, "archive/example")
+    ///         .await?;
+    ///
+    ///     println!("Scraped {:?}", document.metadata);
+    ///     for artifact in artifacts {
+    ///         match artifact.result {
+    ///             Ok(()) => println!("Saved {}", artifact.path.display()),
+    ///             Err(e) => eprintln!("Failed to save {}: {}", artifact.path.display(), e),
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn scrape_to_dir(
+        &self,
+        url: impl AsRef<str>,
+        options: impl Into<Option<ScrapeOptions>>,
+        dir: impl AsRef<Path>,
+    ) -> Result<(Document, Vec<SavedArtifact>), FirecrawlError> {
+        let document = self.scrape(url, options).await?;
+        let dir = dir.as_ref();
+
+        let mut artifacts = Vec::new();
+        if let Some(screenshot) = document.screenshot.as_deref() {
+            artifacts.push(("screenshot".to_string(), screenshot, "png"));
+        }
+        if let Some(actions) = document.actions.as_ref() {
+            for (key, name, extension) in
+                [("screenshots", "screenshot", "png"), ("pdfs", "pdf", "pdf")]
+            {
+                let values = actions.get(key).and_then(Value::as_array);
+                for (i, value) in values
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .enumerate()
+                {
+                    artifacts.push((format!("{}-{}", name, i + 1), value, extension));
+                }
+            }
+        }
+
+        let mut saved = Vec::with_capacity(artifacts.len());
+        for (name, data, default_extension) in artifacts {
+            let path = dir.join(format!(
+                "{}.{}",
+                name,
+                artifact_extension(data).unwrap_or(default_extension)
+            ));
+            let result = save_artifact(self, data, &path).await;
+            saved.push(SavedArtifact { path, result });
+        }
+
+        Ok((document, saved))
+    }
+}
+
+/// Guesses a file extension from a `data:` URL's media type or a URL's path.
+fn artifact_extension(artifact: &str) -> Option<&str> {
+    let extension = if let Some(media_type) = artifact.strip_prefix("data:") {
+        media_type.split([';', ',']).next()?.rsplit('/').next()?
+    } else if artifact.starts_with("http://") || artifact.starts_with("https://") {
+        let path = artifact.split(['?', '#']).next()?;
+        let (_, extension) = path.rsplit_once('/')?.1.rsplit_once('.')?;
+        extension
+    } else {
+        return None;
+    };
+
+    match extension {
+        "jpeg" => Some("jpg"),
+        "png" | "jpg" | "webp" | "gif" | "pdf" => Some(extension),
+        _ => None,
+    }
+}
+
+impl Document {
+    /// Returns the screenshot as raw image bytes.
+    ///
+    /// The API returns screenshots either as a URL or as base64 data (optionally
+    /// as a `data:` URL). Base64 data is decoded directly; URLs are downloaded
+    /// using `client`'s HTTP client, without sending the API key.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the document has no screenshot.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::{Client, Format, ScrapeOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("your-api-key")?;
+    ///
+    ///     let options = ScrapeOptions {
+    ///         formats: Some(vec![Format::Screenshot]),
+    ///         ..Default::default()
+    ///     };
+    ///     let document = client.scrape("https://example.com", options).await?;
+    ///
+    ///     if let Some(bytes) = document.screenshot_bytes(&client).await? {
+    ///         std::fs::write("screenshot.png", bytes)?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn screenshot_bytes(
+        &self,
+        client: &Client,
+    ) -> Result<Option<Vec<u8>>, FirecrawlError> {
+        match self.screenshot.as_deref() {
+            Some(screenshot) => artifact_bytes(client, screenshot).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Returns the bytes of an artifact given as a URL, a `data:` URL or plain
+/// base64 data. URLs are downloaded without sending the API key.
+async fn artifact_bytes(client: &Client, artifact: &str) -> Result<Vec<u8>, FirecrawlError> {
+    if artifact.starts_with("http://") || artifact.starts_with("https://") {
+        let action = "Downloading artifact".to_string();
+        let response = client
+            .client
+            .get(artifact)
+            .send()
+            .await
+            .map_err(|e| FirecrawlError::from_reqwest(action.clone(), e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(FirecrawlError::from_status(action, status, response.url()));
+        }
+
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(FirecrawlError::ResponseParseErrorText)?;
+        return Ok(bytes.to_vec());
+    }
+
+    // Strip a `data:image/png;base64,` style prefix if present
+    let data = match artifact.split_once(";base64,") {
+        Some((prefix, data)) if prefix.starts_with("data:") => data,
+        _ => artifact,
+    };
+
+    base64::engine::general_purpose::STANDARD
+        .decode(data.trim())
+        .map_err(FirecrawlError::ArtifactDecodeError)
+}
+
+/// Downloads or decodes an artifact and writes it to `path`, creating its
+/// directory if needed.
+async fn save_artifact(client: &Client, artifact: &str, path: &Path) -> Result<(), FirecrawlError> {
+    let bytes = artifact_bytes(client, artifact).await?;
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| FirecrawlError::IoError(format!("Creating {}", dir.display()), e))?;
+    }
+    tokio::fs::write(path, bytes)
+        .await
+        .map_err(|e| FirecrawlError::IoError(format!("Writing {}", path.display()), e))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::v2::{ClientConfig, MemoryScrapeCache, ScrollDirection};
     use serde_json::json;
+    use std::sync::Arc;
 
     #[tokio::test]
     async fn test_scrape_with_mock() {
@@ -329,24 +757,63 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_scrape_with_schema() {
+    async fn test_scrape_with_page_headers() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .match_header("authorization", "Bearer test_key")
+            .match_header("cookie", mockito::Matcher::Missing)
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "url": "https://example.com",
+                "headers": {
+                    "Cookie": "session=abc",
+                    "User-Agent": "custom-agent"
+                }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "data": { "markdown": "# Hi" } }).to_string())
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = ScrapeOptions {
+            headers: Some(HashMap::from([
+                ("Cookie".to_string(), "session=abc".to_string()),
+                ("User-Agent".to_string(), "custom-agent".to_string()),
+            ])),
+            ..Default::default()
+        };
+        let document = client.scrape("https://example.com", options).await.unwrap();
+
+        assert_eq!(document.markdown.as_deref(), Some("# Hi"));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_with_change_tracking() {
+        use crate::v2::{ChangeStatus, ChangeTrackingMode, PageVisibility};
+
         let mut server = mockito::Server::new_async().await;
 
         let mock = server
             .mock("POST", "/v2/scrape")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "formats": ["markdown", "changeTracking"],
+                "changeTrackingOptions": { "modes": ["git-diff"], "tag": "daily" }
+            })))
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(
                 json!({
                     "success": true,
                     "data": {
-                        "json": {
-                            "title": "Product Name",
-                            "price": 99.99
-                        },
-                        "metadata": {
-                            "sourceURL": "https://example.com/product",
-                            "statusCode": 200
+                        "markdown": "# Pricing",
+                        "changeTracking": {
+                            "previousScrapeAt": "2025-01-01T00:00:00Z",
+                            "changeStatus": "changed",
+                            "visibility": "visible",
+                            "diff": { "text": "-$10\n+$12" }
                         }
                     }
                 })
@@ -355,50 +822,759 @@ mod tests {
             .create();
 
         let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
-
-        let schema = json!({
-            "type": "object",
-            "properties": {
-                "title": { "type": "string" },
-                "price": { "type": "number" }
-            }
-        });
-
-        let data = client
-            .scrape_with_schema(
-                "https://example.com/product",
-                schema,
-                Some("Extract product info"),
-            )
+        let options = ScrapeOptions {
+            formats: Some(vec![Format::Markdown, Format::ChangeTracking]),
+            change_tracking_options: Some(ChangeTrackingOptions {
+                modes: Some(vec![ChangeTrackingMode::GitDiff]),
+                tag: Some("daily".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let document = client
+            .scrape("https://example.com/pricing", options)
             .await
             .unwrap();
 
-        assert_eq!(data["title"], "Product Name");
-        assert_eq!(data["price"], 99.99);
+        let change_tracking = document.parsed_change_tracking().unwrap();
+        assert_eq!(
+            change_tracking.previous_scrape_at.as_deref(),
+            Some("2025-01-01T00:00:00Z")
+        );
+        assert_eq!(change_tracking.change_status, Some(ChangeStatus::Changed));
+        assert_eq!(change_tracking.visibility, Some(PageVisibility::Visible));
+        assert_eq!(change_tracking.diff.unwrap()["text"], "-$10\n+$12");
+        assert!(change_tracking.json.is_none());
         mock.assert();
     }
 
     #[tokio::test]
-    async fn test_scrape_error_response() {
+    async fn test_scrape_timed() {
         let mut server = mockito::Server::new_async().await;
 
         let mock = server
             .mock("POST", "/v2/scrape")
-            .with_status(400)
+            .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(50));
                 json!({
-                    "success": false,
-                    "error": "Invalid URL"
+                    "success": true,
+                    "data": { "markdown": "# Delayed" }
                 })
-                .to_string(),
-            )
+                .to_string()
+                .into()
+            })
             .create();
 
         let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
-        let result = client.scrape("invalid-url", None).await;
+        let (document, elapsed) = client
+            .scrape_timed("https://example.com", None)
+            .await
+            .unwrap();
 
-        assert!(result.is_err());
+        assert_eq!(document.markdown.as_deref(), Some("# Delayed"));
+        assert!(elapsed >= Duration::from_millis(50));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_timed_bypasses_cache() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "data": { "markdown": "# Fresh" } }).to_string())
+            .expect(2)
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_config(ClientConfig {
+                scrape_cache: Some(Arc::new(MemoryScrapeCache::new(Duration::from_secs(60)))),
+                ..Default::default()
+            })
+            .unwrap();
+        client.scrape("https://example.com", None).await.unwrap();
+        let (document, _) = client
+            .scrape_timed("https://example.com", None)
+            .await
+            .unwrap();
+
+        assert_eq!(document.markdown.as_deref(), Some("# Fresh"));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_markdown() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .match_body(mockito::Matcher::Json(json!({
+                "url": "https://example.com",
+                "formats": ["markdown"]
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "markdown": "# Example Domain",
+                        "metadata": { "sourceURL": "https://example.com", "statusCode": 200 }
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let markdown = client.scrape_markdown("https://example.com").await.unwrap();
+
+        assert_eq!(markdown, "# Example Domain");
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_with_extra_fields() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .match_body(mockito::Matcher::Json(json!({
+                "url": "https://example.com",
+                "formats": ["markdown"],
+                "experimentalFeature": { "enabled": true }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "data": { "markdown": "# Example" } }).to_string())
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = ScrapeOptions {
+            formats: Some(vec![Format::Markdown]),
+            extra: json!({ "experimentalFeature": { "enabled": true } })
+                .as_object()
+                .cloned(),
+            ..Default::default()
+        };
+        client.scrape("https://example.com", options).await.unwrap();
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_extra_fields_do_not_override_known_fields() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .match_body(mockito::Matcher::Json(json!({
+                "url": "https://example.com",
+                "formats": ["markdown"],
+                "onlyMainContent": false
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "data": { "markdown": "# Example" } }).to_string())
+            .create();
+
+        let options = ScrapeOptions {
+            formats: Some(vec![Format::Markdown]),
+            extra: json!({
+                "url": "https://other.example",
+                "formats": ["html"],
+                "onlyMainContent": false
+            })
+            .as_object()
+            .cloned(),
+            ..Default::default()
+        };
+
+        // Nested options, e.g. a search's `scrapeOptions`, merge the same way
+        let nested = serde_json::to_string(&options).unwrap();
+        assert_eq!(nested.matches("\"formats\"").count(), 1);
+        assert_eq!(
+            serde_json::from_str::<Value>(&nested).unwrap(),
+            json!({
+                "url": "https://other.example",
+                "formats": ["markdown"],
+                "onlyMainContent": false
+            })
+        );
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        client.scrape("https://example.com", options).await.unwrap();
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_cache() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "data": { "markdown": "# Example" } }).to_string())
+            .expect(3)
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_config(ClientConfig {
+                scrape_cache: Some(Arc::new(MemoryScrapeCache::new(Duration::from_secs(60)))),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let first = client.scrape("https://example.com/", None).await.unwrap();
+        // Same normalized URL and options: served from the cache
+        let second = client
+            .scrape("https://EXAMPLE.com/#top", None)
+            .await
+            .unwrap();
+        assert_eq!(first.markdown, second.markdown);
+
+        // Different options: a new request
+        let options = ScrapeOptions {
+            mobile: Some(true),
+            ..Default::default()
+        };
+        client
+            .scrape("https://example.com/", options)
+            .await
+            .unwrap();
+
+        // Different formats: a new request, then served from its own entry
+        let options = ScrapeOptions {
+            formats: Some(vec![Format::Html]),
+            ..Default::default()
+        };
+        client
+            .scrape("https://example.com/", options.clone())
+            .await
+            .unwrap();
+        client
+            .scrape("https://example.com/", options)
+            .await
+            .unwrap();
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_cache_skips_zero_data_retention() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "data": { "markdown": "# Example" } }).to_string())
+            .expect(2)
+            .create();
+
+        let cache = Arc::new(MemoryScrapeCache::new(Duration::from_secs(60)));
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_config(ClientConfig {
+                scrape_cache: Some(cache.clone()),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let options = ScrapeOptions {
+            zero_data_retention: Some(true),
+            ..Default::default()
+        };
+        for _ in 0..2 {
+            client
+                .scrape("https://example.com/", options.clone())
+                .await
+                .unwrap();
+        }
+
+        assert!(format!("{:?}", cache).contains("entries: 0"));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_markdown_missing() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "metadata": { "sourceURL": "https://example.com", "statusCode": 200 }
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let result = client.scrape_markdown("https://example.com").await;
+
+        assert!(matches!(
+            result,
+            Err(FirecrawlError::MissingFormat(ref format)) if format == "markdown"
+        ));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_with_schema() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "json": {
+                            "title": "Product Name",
+                            "price": 99.99
+                        },
+                        "metadata": {
+                            "sourceURL": "https://example.com/product",
+                            "statusCode": 200
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "title": { "type": "string" },
+                "price": { "type": "number" }
+            }
+        });
+
+        let data = client
+            .scrape_with_schema(
+                "https://example.com/product",
+                schema,
+                Some("Extract product info"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(data["title"], "Product Name");
+        assert_eq!(data["price"], 99.99);
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_with_actions() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "url": "https://example.com",
+                "actions": [
+                    { "type": "click", "selector": "#load-more" },
+                    { "type": "wait", "milliseconds": 1000 },
+                    { "type": "write", "text": "firecrawl" },
+                    { "type": "press", "key": "Enter" },
+                    { "type": "scroll", "direction": "down" },
+                    { "type": "screenshot" }
+                ]
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "markdown": "# Loaded",
+                        "actions": { "screenshots": ["https://example.com/shot.png"] }
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let actions = vec![
+            Action::Click {
+                selector: "#load-more".to_string(),
+            },
+            Action::Wait {
+                milliseconds: Some(1000),
+                selector: None,
+            },
+            Action::Write {
+                text: "firecrawl".to_string(),
+            },
+            Action::Press {
+                key: "Enter".to_string(),
+            },
+            Action::Scroll {
+                direction: ScrollDirection::Down,
+                selector: None,
+            },
+            Action::Screenshot {
+                full_page: None,
+                quality: None,
+                viewport: None,
+            },
+        ];
+
+        let document = client
+            .scrape_with_actions("https://example.com", actions, None)
+            .await
+            .unwrap();
+
+        assert_eq!(document.markdown, Some("# Loaded".to_string()));
+        assert!(document.actions.is_some());
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_strict_warnings() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "markdown": "",
+                        "warning": "Page appears to be empty"
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_config(ClientConfig {
+                strict_warnings: true,
+                ..Default::default()
+            })
+            .unwrap();
+        let result = client.scrape("https://example.com", None).await;
+
+        assert!(matches!(
+            result,
+            Err(FirecrawlError::StrictWarnings(action, warnings))
+                if action == "scrape" && warnings == ["Page appears to be empty"]
+        ));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_error_response() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": false,
+                    "error": "Invalid URL"
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let result = client.scrape("invalid-url", None).await;
+
+        assert!(result.is_err());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_wait_for_and_mobile_serialization() {
+        let body = serde_json::to_value(ScrapeOptions::default()).unwrap();
+        assert!(body.get("waitFor").is_none());
+        assert!(body.get("mobile").is_none());
+
+        let options = ScrapeOptions {
+            wait_for: Some(2000),
+            mobile: Some(true),
+            ..Default::default()
+        };
+        let body = serde_json::to_value(&options).unwrap();
+        assert_eq!(body["waitFor"], 2000);
+        assert_eq!(body["mobile"], true);
+    }
+
+    #[tokio::test]
+    async fn test_screenshot_bytes_from_base64() {
+        let client = Client::new_selfhosted("http://localhost:3000", None::<&str>).unwrap();
+
+        let document = Document {
+            screenshot: Some("data:image/png;base64,iVBORw0KGgo=".to_string()),
+            ..Default::default()
+        };
+        let bytes = document.screenshot_bytes(&client).await.unwrap().unwrap();
+        assert_eq!(bytes, b"\x89PNG\r\n\x1a\n");
+
+        let document = Document {
+            screenshot: Some("iVBORw0KGgo=".to_string()),
+            ..Default::default()
+        };
+        let bytes = document.screenshot_bytes(&client).await.unwrap().unwrap();
+        assert_eq!(bytes, b"\x89PNG\r\n\x1a\n");
+
+        let document = Document::default();
+        assert!(document.screenshot_bytes(&client).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_screenshot_bytes_from_url() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/screenshots/page.png")
+            .match_header("authorization", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "image/png")
+            .with_body(b"\x89PNG\r\n\x1a\n")
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let document = Document {
+            screenshot: Some(format!("{}/screenshots/page.png", server.url())),
+            ..Default::default()
+        };
+        let bytes = document.screenshot_bytes(&client).await.unwrap().unwrap();
+
+        assert_eq!(bytes, b"\x89PNG\r\n\x1a\n");
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_screenshot_bytes_invalid_base64() {
+        let client = Client::new_selfhosted("http://localhost:3000", None::<&str>).unwrap();
+        let document = Document {
+            screenshot: Some("not base64!".to_string()),
+            ..Default::default()
+        };
+
+        let result = document.screenshot_bytes(&client).await;
+        assert!(matches!(
+            result,
+            Err(FirecrawlError::ArtifactDecodeError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_scrape_to_dir() {
+        let mut server = mockito::Server::new_async().await;
+
+        let scrape_mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "markdown": "# Archived",
+                        "screenshot": "data:image/jpeg;base64,/9j/4AAQ",
+                        "actions": {
+                            "pdfs": [format!("{}/files/page.pdf?token=abc", server.url())]
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+        let pdf_mock = server
+            .mock("GET", "/files/page.pdf?token=abc")
+            .match_header("authorization", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_body(b"%PDF-1.7")
+            .create();
+
+        let dir = std::env::temp_dir().join(format!("firecrawl-{}", uuid::Uuid::new_v4()));
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let (document, artifacts) = client
+            .scrape_to_dir("https://example.com", None, &dir)
+            .await
+            .unwrap();
+
+        assert_eq!(document.markdown.as_deref(), Some("# Archived"));
+        let paths: Vec<_> = artifacts
+            .into_iter()
+            .map(|artifact| {
+                artifact.result.unwrap();
+                artifact.path
+            })
+            .collect();
+        assert_eq!(
+            paths,
+            vec![dir.join("screenshot.jpg"), dir.join("pdf-1.pdf")]
+        );
+        assert_eq!(
+            std::fs::read(&paths[0]).unwrap(),
+            b"\xff\xd8\xff\xe0\x00\x10"
+        );
+        assert_eq!(std::fs::read(&paths[1]).unwrap(), b"%PDF-1.7");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        scrape_mock.assert();
+        pdf_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_to_dir_keeps_document_on_artifact_errors() {
+        let mut server = mockito::Server::new_async().await;
+
+        let scrape_mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "markdown": "# Archived",
+                        "screenshot": "not base64!",
+                        "actions": {
+                            "pdfs": [
+                                format!("{}/files/missing.pdf", server.url()),
+                                "data:application/pdf;base64,JVBERi0xLjc="
+                            ]
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+        let pdf_mock = server
+            .mock("GET", "/files/missing.pdf")
+            .with_status(404)
+            .create();
+
+        let dir = std::env::temp_dir().join(format!("firecrawl-{}", uuid::Uuid::new_v4()));
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let (document, artifacts) = client
+            .scrape_to_dir("https://example.com", None, &dir)
+            .await
+            .unwrap();
+
+        assert_eq!(document.markdown.as_deref(), Some("# Archived"));
+        assert_eq!(artifacts.len(), 3);
+        assert!(matches!(
+            artifacts[0].result,
+            Err(FirecrawlError::ArtifactDecodeError(_))
+        ));
+        assert!(matches!(
+            artifacts[1].result,
+            Err(FirecrawlError::HttpRequestFailed(_, 404, _))
+        ));
+        assert!(artifacts[2].result.is_ok());
+        assert_eq!(std::fs::read(&artifacts[2].path).unwrap(), b"%PDF-1.7");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        scrape_mock.assert();
+        pdf_mock.assert();
+    }
+
+    #[test]
+    fn test_artifact_extension() {
+        assert_eq!(
+            artifact_extension("data:image/png;base64,iVBO"),
+            Some("png")
+        );
+        assert_eq!(
+            artifact_extension("data:image/jpeg;base64,/9j/"),
+            Some("jpg")
+        );
+        assert_eq!(
+            artifact_extension("https://cdn.example.com/shots/page.webp?x=1"),
+            Some("webp")
+        );
+        assert_eq!(
+            artifact_extension("https://cdn.example.com/shots/page"),
+            None
+        );
+        assert_eq!(artifact_extension("iVBORw0KGgo="), None);
+    }
+
+    #[test]
+    fn test_privacy_flags_serialization() {
+        let body = serde_json::to_value(ScrapeOptions::default()).unwrap();
+        assert!(body.get("zeroDataRetention").is_none());
+        assert!(body.get("storeInCache").is_none());
+
+        let options = ScrapeOptions {
+            zero_data_retention: Some(true),
+            store_in_cache: Some(false),
+            ..Default::default()
+        };
+        let body = serde_json::to_value(&options).unwrap();
+        assert_eq!(
+            body,
+            json!({ "zeroDataRetention": true, "storeInCache": false })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scrape_with_max_age() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .match_body(mockito::Matcher::Json(json!({
+                "url": "https://example.com",
+                "maxAge": 3_600_000
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "markdown": "# Cached",
+                        "metadata": { "cacheState": "hit", "cachedAt": "2025-01-01T00:00:00Z" }
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = ScrapeOptions {
+            max_age: Some(3_600_000),
+            ..Default::default()
+        };
+        let document = client.scrape("https://example.com", options).await.unwrap();
+
+        assert_eq!(
+            document.metadata.unwrap().cache_state.as_deref(),
+            Some("hit")
+        );
         mock.assert();
     }
 }
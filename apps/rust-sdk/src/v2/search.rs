@@ -1,18 +1,29 @@
 //! Search endpoint for Firecrawl API v2.
 
+use futures::future::{BoxFuture, FutureExt, Shared, WeakShared};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
-use super::client::Client;
+use super::client::{json_body, merge_extra, Client};
 use super::scrape::ScrapeOptions;
 use super::types::{
     Document, SearchCategory, SearchResultImage, SearchResultNews, SearchResultWeb, SearchSource,
 };
 use crate::FirecrawlError;
 
+/// Maximum number of concurrent requests issued by `Client::search_many`.
+const SEARCH_MANY_CONCURRENCY: usize = 4;
+
 /// Options for search requests.
 #[serde_with::skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, Clone)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", remote = "Self")]
 pub struct SearchOptions {
     /// Maximum number of results to return. Default: 5, Max: 20.
     pub limit: Option<u32>,
@@ -32,14 +43,45 @@ pub struct SearchOptions {
     /// Whether to ignore invalid URLs in results.
     pub ignore_invalid_urls: Option<bool>,
 
-    /// Timeout in milliseconds.
+    /// Timeout for the whole search request in milliseconds.
     pub timeout: Option<u32>,
 
     /// Scrape options to apply to each search result.
     pub scrape_options: Option<ScrapeOptions>,
 
+    /// Timeout for scraping each individual result in milliseconds.
+    ///
+    /// Sent as `scrapeOptions.timeout` and takes precedence over the timeout set there,
+    /// so that one slow page does not exhaust the overall search `timeout`.
+    /// Has no effect unless `scrape_options` is set.
+    #[serde(skip)]
+    pub scrape_timeout: Option<u32>,
+
     /// Integration identifier for tracking.
     pub integration: Option<String>,
+
+    /// Additional fields merged into the request body, for API features the
+    /// SDK does not model yet. Typed fields take precedence over fields of the
+    /// same name here.
+    #[serde(flatten, skip_serializing)]
+    pub extra: Option<Map<String, Value>>,
+}
+
+// `remote = "Self"` turns the derived impls into inherent functions, so these
+// impls can merge `extra` after the typed fields.
+impl Serialize for SearchOptions {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut value = SearchOptions::serialize(self, serde_json::value::Serializer)
+            .map_err(serde::ser::Error::custom)?;
+        merge_extra(&mut value, self.extra.as_ref());
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchOptions {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SearchOptions::deserialize(deserializer)
+    }
 }
 
 /// Request body for search endpoint.
@@ -68,6 +110,7 @@ pub struct SearchData {
 ///
 /// Uses custom deserialization to properly distinguish between web results
 /// and scraped documents by checking for document-specific fields.
+#[allow(clippy::large_enum_variant)]
 #[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum SearchResultOrDocument {
@@ -77,23 +120,36 @@ pub enum SearchResultOrDocument {
     Document(Document),
 }
 
+impl SearchResultOrDocument {
+    /// Returns the result's URL: the `url` of a web result, or the source URL
+    /// of a scraped document.
+    pub fn url(&self) -> Option<String> {
+        match self {
+            SearchResultOrDocument::WebResult(result) => Some(result.url.clone()),
+            SearchResultOrDocument::Document(doc) => {
+                doc.metadata.as_ref().and_then(|m| m.source_url.clone())
+            }
+        }
+    }
+}
+
+/// Checks for document-specific fields that indicate scraped content.
+fn is_document_value(value: &Value) -> bool {
+    value.get("markdown").is_some()
+        || value.get("html").is_some()
+        || value.get("rawHtml").is_some()
+        || value.get("metadata").is_some()
+}
+
 impl<'de> serde::Deserialize<'de> for SearchResultOrDocument {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        use serde_json::Value;
-
         let value = Value::deserialize(deserializer)?;
 
-        // Check for document-specific fields that indicate scraped content
-        // If any of these exist, it's a Document, not a simple WebResult
-        let is_document = value.get("markdown").is_some()
-            || value.get("html").is_some()
-            || value.get("rawHtml").is_some()
-            || value.get("metadata").is_some();
-
-        if is_document {
+        // If any document fields exist, it's a Document, not a simple WebResult
+        if is_document_value(&value) {
             Document::deserialize(value)
                 .map(SearchResultOrDocument::Document)
                 .map_err(serde::de::Error::custom)
@@ -105,8 +161,84 @@ impl<'de> serde::Deserialize<'de> for SearchResultOrDocument {
     }
 }
 
+/// A web search result together with its scraped content, if any.
+///
+/// Returned by [`Client::search_scraped`].
+#[derive(Serialize, Debug, Clone)]
+pub struct ScrapedSearchResult {
+    /// The search engine's result: URL, title and description.
+    pub web: SearchResultWeb,
+    /// The scraped page, or `None` if the result was not scraped.
+    pub document: Option<Document>,
+}
+
+impl<'de> serde::Deserialize<'de> for ScrapedSearchResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut value = Value::deserialize(deserializer)?;
+
+        let document = if is_document_value(&value) {
+            // Scraped results may omit the top-level URL; fall back to the source URL
+            if !value["url"].is_string() {
+                if let Some(source_url) = value.pointer("/metadata/sourceURL").cloned() {
+                    value["url"] = source_url;
+                }
+            }
+            Some(Document::deserialize(&value).map_err(serde::de::Error::custom)?)
+        } else {
+            None
+        };
+        let web = SearchResultWeb::deserialize(&value).map_err(serde::de::Error::custom)?;
+
+        Ok(ScrapedSearchResult { web, document })
+    }
+}
+
+/// Response from search endpoint, as returned by [`Client::search_scraped`].
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrapedSearchResponse {
+    /// Whether the request was successful.
+    pub success: bool,
+    /// Search results data.
+    pub data: ScrapedSearchData,
+    /// Warning message if any.
+    pub warning: Option<String>,
+}
+
+/// Web results of a search, each with its scraped content.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ScrapedSearchData {
+    /// Web results in ranking order. Results with neither a `url` nor a
+    /// `sourceURL` cannot be attributed to a page and are skipped.
+    #[serde(default, deserialize_with = "deserialize_scraped_results")]
+    pub web: Vec<ScrapedSearchResult>,
+}
+
+/// Deserializes scraped web results, skipping results without a URL.
+fn deserialize_scraped_results<'de, D>(
+    deserializer: D,
+) -> Result<Vec<ScrapedSearchResult>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Option::<Vec<Value>>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|value| {
+            value["url"].is_string()
+                || value
+                    .pointer("/metadata/sourceURL")
+                    .is_some_and(Value::is_string)
+        })
+        .map(|value| ScrapedSearchResult::deserialize(value).map_err(serde::de::Error::custom))
+        .collect()
+}
+
 /// Response from search endpoint.
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct SearchResponse {
     /// Whether the request was successful.
@@ -117,6 +249,135 @@ pub struct SearchResponse {
     pub warning: Option<String>,
 }
 
+impl SearchResponse {
+    /// Returns `true` if the search or any of its scraped results carries a
+    /// warning or scrape error, i.e. the results may be incomplete.
+    pub fn has_warning(&self) -> bool {
+        !self.warnings().is_empty()
+    }
+
+    /// Collects the response-level warning and per-result warnings and scrape
+    /// errors into one list.
+    pub fn warnings(&self) -> Vec<SearchWarning> {
+        let documents = self
+            .data
+            .web
+            .iter()
+            .flatten()
+            .filter_map(|result| match result {
+                SearchResultOrDocument::Document(doc) => Some(doc),
+                SearchResultOrDocument::WebResult(_) => None,
+            });
+
+        collect_warnings(self.warning.as_ref(), documents)
+    }
+}
+
+/// Search responses checked by `ClientConfig::strict_warnings`.
+trait SearchWarnings {
+    fn search_warnings(&self) -> Vec<SearchWarning>;
+}
+
+impl SearchWarnings for SearchResponse {
+    fn search_warnings(&self) -> Vec<SearchWarning> {
+        self.warnings()
+    }
+}
+
+impl SearchWarnings for ScrapedSearchResponse {
+    fn search_warnings(&self) -> Vec<SearchWarning> {
+        let documents = self
+            .data
+            .web
+            .iter()
+            .filter_map(|result| result.document.as_ref());
+
+        collect_warnings(self.warning.as_ref(), documents)
+    }
+}
+
+/// Collects a response-level warning plus each scraped document's warning and
+/// scrape error.
+fn collect_warnings<'a>(
+    warning: Option<&String>,
+    documents: impl Iterator<Item = &'a Document>,
+) -> Vec<SearchWarning> {
+    let mut warnings: Vec<SearchWarning> = warning
+        .map(|message| SearchWarning {
+            url: None,
+            message: message.clone(),
+        })
+        .into_iter()
+        .collect();
+
+    for doc in documents {
+        let url = doc.metadata.as_ref().and_then(|m| m.source_url.clone());
+        let error = doc.metadata.as_ref().and_then(|m| m.error.as_ref());
+        for message in doc.warning.iter().chain(error) {
+            warnings.push(SearchWarning {
+                url: url.clone(),
+                message: message.clone(),
+            });
+        }
+    }
+
+    warnings
+}
+
+/// A warning about a search response, or a scraped result within it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchWarning {
+    /// URL of the affected result, or `None` for a response-level warning.
+    pub url: Option<String>,
+    /// The warning or scrape error message.
+    pub message: String,
+}
+
+type SharedSearchFuture = BoxFuture<'static, Result<SearchResponse, Arc<FirecrawlError>>>;
+type SharedSearch = Shared<SharedSearchFuture>;
+
+/// Searches currently in flight, keyed by query and serialized options. Shared by
+/// clones of a `Client`.
+///
+/// Entries are weak, so a search every caller gave up on is dropped rather than
+/// kept alive by the map; each search removes its own entry through an
+/// [`InFlightEntry`] guard.
+#[derive(Clone, Default)]
+pub(crate) struct InFlightSearches(Arc<Mutex<HashMap<String, WeakShared<SharedSearchFuture>>>>);
+
+impl fmt::Debug for InFlightSearches {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self.0.lock().unwrap_or_else(PoisonError::into_inner).len();
+        f.debug_tuple("InFlightSearches").field(&len).finish()
+    }
+}
+
+/// Removes a search's entry from [`InFlightSearches`] when the search
+/// completes or is dropped because every caller gave up on it.
+struct InFlightEntry {
+    searches: InFlightSearches,
+    key: String,
+    completed: bool,
+}
+
+impl Drop for InFlightEntry {
+    fn drop(&mut self) {
+        let mut searches = self
+            .searches
+            .0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        // A dropped search may already have been replaced by a live one
+        let is_own = self.completed
+            || searches
+                .get(&self.key)
+                .is_some_and(|search| search.upgrade().is_none());
+        if is_own {
+            searches.remove(&self.key);
+        }
+    }
+}
+
 impl Client {
     /// Searches the web and optionally scrapes the results.
     ///
@@ -167,9 +428,87 @@ impl Client {
         query: impl AsRef<str>,
         options: impl Into<Option<SearchOptions>>,
     ) -> Result<SearchResponse, FirecrawlError> {
+        if self.config.coalesce_searches {
+            self.coalesced_search(query.as_ref().to_string(), options.into())
+                .await
+        } else {
+            self.post_search(query, options.into()).await
+        }
+    }
+
+    /// Runs a search, joining an identical search already in flight if there is
+    /// one.
+    async fn coalesced_search(
+        &self,
+        query: String,
+        options: Option<SearchOptions>,
+    ) -> Result<SearchResponse, FirecrawlError> {
+        // `scrape_timeout` is not serialized with the options but changes the request.
+        // Going through `Value` sorts map keys, so equal options give equal keys.
+        let scrape_timeout = options.as_ref().and_then(|o| o.scrape_timeout);
+        let key = serde_json::to_value((&query, &options, scrape_timeout))
+            .map_err(FirecrawlError::RequestSerializeError)?
+            .to_string();
+
+        let search: SharedSearch = {
+            let mut in_flight = self
+                .in_flight_searches
+                .0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            match in_flight.get(&key).and_then(WeakShared::upgrade) {
+                Some(search) => search,
+                None => {
+                    // Detached from the in-flight map, which only the guard holds
+                    let client = Client {
+                        in_flight_searches: InFlightSearches::default(),
+                        ..self.clone()
+                    };
+                    let mut entry = InFlightEntry {
+                        searches: self.in_flight_searches.clone(),
+                        key: key.clone(),
+                        completed: false,
+                    };
+                    let search = async move {
+                        let result = client.post_search(query, options).await.map_err(Arc::new);
+                        // Removed before completing so later calls issue a fresh request
+                        entry.completed = true;
+                        drop(entry);
+                        result
+                    }
+                    .boxed()
+                    .shared();
+                    if let Some(weak) = search.downgrade() {
+                        in_flight.insert(key, weak);
+                    }
+                    search
+                }
+            }
+        };
+
+        // Every caller gets the same error, rebuilt from the shared one
+        search.await.map_err(|e| FirecrawlError::from_shared(&e))
+    }
+
+    /// Sends a search request and parses the response as `T`, applying
+    /// `ClientConfig::strict_warnings`.
+    async fn post_search<T: DeserializeOwned + SearchWarnings>(
+        &self,
+        query: impl AsRef<str>,
+        options: Option<SearchOptions>,
+    ) -> Result<T, FirecrawlError> {
+        let mut options = options.unwrap_or_default();
+        if let (Some(scrape_options), Some(scrape_timeout)) =
+            (options.scrape_options.as_mut(), options.scrape_timeout)
+        {
+            scrape_options.timeout = Some(scrape_timeout);
+        }
+
+        // Extra fields go on the top level, below `query`
+        let extra = options.extra.take();
         let body = SearchRequest {
             query: query.as_ref().to_string(),
-            options: options.into().unwrap_or_default(),
+            options,
         };
 
         let headers = self.prepare_headers(None);
@@ -178,14 +517,138 @@ impl Client {
             .client
             .post(self.url("/search"))
             .headers(headers)
-            .json(&body)
+            .json(&json_body(&body, extra.as_ref())?)
             .send()
             .await
             .map_err(|e| {
-                FirecrawlError::HttpError(format!("Searching for {:?}", query.as_ref()), e)
+                FirecrawlError::from_reqwest(format!("Searching for {:?}", query.as_ref()), e)
             })?;
 
-        self.handle_response(response, "search").await
+        let response: T = self.handle_response(response, "search").await?;
+        self.check_warnings(
+            "search",
+            response
+                .search_warnings()
+                .into_iter()
+                .map(|warning| match warning.url {
+                    Some(url) => format!("{}: {}", url, warning.message),
+                    None => warning.message,
+                }),
+        )?;
+
+        Ok(response)
+    }
+
+    /// Searches the web, keeping each result's search metadata next to its
+    /// scraped content.
+    ///
+    /// With `scrape_options` set, [`Client::search`] returns scraped results as
+    /// plain `Document`s, losing the title and description from the search
+    /// engine. This returns both halves for every web result, in ranking order;
+    /// `document` is `None` for results that were not scraped. The response's
+    /// `warning` is kept as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query string.
+    /// * `options` - Optional search configuration; set `scrape_options` to scrape results.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::{Client, ScrapeOptions, SearchOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("your-api-key")?;
+    ///
+    ///     let options = SearchOptions {
+    ///         scrape_options: Some(ScrapeOptions::default()),
+    ///         ..Default::default()
+    ///     };
+    ///     let response = client.search_scraped("rust", options).await?;
+    ///     for (rank, result) in response.data.web.iter().enumerate() {
+    ///         println!("#{} {:?}: {}", rank + 1, result.web.title, result.web.url);
+    ///         if let Some(doc) = &result.document {
+    ///             println!("{:?}", doc.markdown);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn search_scraped(
+        &self,
+        query: impl AsRef<str>,
+        options: impl Into<Option<SearchOptions>>,
+    ) -> Result<ScrapedSearchResponse, FirecrawlError> {
+        self.post_search(query, options.into()).await
+    }
+
+    /// Searches the web and reports how long the request took.
+    ///
+    /// Behaves like [`Client::search`], additionally returning the time spent
+    /// sending the request and handling the response.
+    pub async fn search_timed(
+        &self,
+        query: impl AsRef<str>,
+        options: impl Into<Option<SearchOptions>>,
+    ) -> Result<(SearchResponse, Duration), FirecrawlError> {
+        let start = Instant::now();
+        let response = self.search(query, options).await?;
+        Ok((response, start.elapsed()))
+    }
+
+    /// Runs several searches concurrently with the same options.
+    ///
+    /// At most four searches are in flight at once. Results are returned in
+    /// the same order as `queries`, each paired with its query.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - The search queries to run.
+    /// * `options` - Optional search configuration, applied to every query.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered; remaining searches are abandoned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("your-api-key")?;
+    ///
+    ///     let queries = vec!["rust async".to_string(), "rust serde".to_string()];
+    ///     for (query, response) in client.search_many(queries, None).await? {
+    ///         let count = response.data.web.map(|web| web.len()).unwrap_or(0);
+    ///         println!("{}: {} results", query, count);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn search_many(
+        &self,
+        queries: Vec<String>,
+        options: impl Into<Option<SearchOptions>>,
+    ) -> Result<Vec<(String, SearchResponse)>, FirecrawlError> {
+        let options = options.into();
+
+        stream::iter(queries)
+            .map(|query| {
+                let options = options.clone();
+                async move {
+                    let response = self.search(&query, options).await?;
+                    Ok((query, response))
+                }
+            })
+            .buffered(SEARCH_MANY_CONCURRENCY)
+            .try_collect()
+            .await
     }
 
     /// Searches the web and scrapes the results.
@@ -224,6 +687,45 @@ impl Client {
         query: impl AsRef<str>,
         limit: u32,
     ) -> Result<Vec<Document>, FirecrawlError> {
+        let outcome = self.search_and_scrape_outcome(query, limit).await?;
+        Ok(outcome.documents)
+    }
+
+    /// Searches the web and scrapes the results, reporting results that could
+    /// not be scraped.
+    ///
+    /// Like [`Client::search_and_scrape`], but web results that came back
+    /// without scraped content are returned in `unscraped` instead of being
+    /// dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query string.
+    /// * `limit` - Maximum number of results to return.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("your-api-key")?;
+    ///
+    ///     let outcome = client.search_and_scrape_outcome("rust programming", 5).await?;
+    ///     println!("Scraped {} pages", outcome.documents.len());
+    ///     for result in outcome.unscraped {
+    ///         println!("Not scraped: {}", result.url);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn search_and_scrape_outcome(
+        &self,
+        query: impl AsRef<str>,
+        limit: u32,
+    ) -> Result<SearchScrapeOutcome, FirecrawlError> {
         let options = SearchOptions {
             limit: Some(limit),
             scrape_options: Some(ScrapeOptions::default()),
@@ -232,24 +734,31 @@ impl Client {
 
         let response = self.search(query, options).await?;
 
-        let documents: Vec<Document> = response
-            .data
-            .web
-            .unwrap_or_default()
-            .into_iter()
-            .filter_map(|result| match result {
-                SearchResultOrDocument::Document(doc) => Some(doc),
-                SearchResultOrDocument::WebResult(_) => None,
-            })
-            .collect();
+        let mut outcome = SearchScrapeOutcome::default();
+        for result in response.data.web.unwrap_or_default() {
+            match result {
+                SearchResultOrDocument::Document(doc) => outcome.documents.push(doc),
+                SearchResultOrDocument::WebResult(web) => outcome.unscraped.push(web),
+            }
+        }
 
-        Ok(documents)
+        Ok(outcome)
     }
 }
 
+/// Result of [`Client::search_and_scrape_outcome`].
+#[derive(Debug, Default, Clone)]
+pub struct SearchScrapeOutcome {
+    /// Results that were scraped, in ranking order.
+    pub documents: Vec<Document>,
+    /// Results that came back without scraped content, in ranking order.
+    pub unscraped: Vec<SearchResultWeb>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::v2::{ClientConfig, Format};
     use serde_json::json;
 
     #[tokio::test]
@@ -291,6 +800,557 @@ mod tests {
         mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_search_many_preserves_order() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mut mocks = Vec::new();
+        for (query, url, delay) in [
+            ("first", "https://first.example", 100),
+            ("second", "https://second.example", 0),
+        ] {
+            mocks.push(
+                server
+                    .mock("POST", "/v2/search")
+                    .match_body(mockito::Matcher::PartialJson(json!({ "query": query })))
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body_from_request(move |_| {
+                        // Delay the first query so responses complete out of order
+                        std::thread::sleep(Duration::from_millis(delay));
+                        json!({
+                            "success": true,
+                            "data": { "web": [{ "url": url, "title": query }] }
+                        })
+                        .to_string()
+                        .into()
+                    })
+                    .create(),
+            );
+        }
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let results = client
+            .search_many(vec!["first".to_string(), "second".to_string()], None)
+            .await
+            .unwrap();
+
+        let queries: Vec<_> = results.iter().map(|(query, _)| query.as_str()).collect();
+        assert_eq!(queries, ["first", "second"]);
+        for (query, response) in &results {
+            match &response.data.web.as_ref().unwrap()[0] {
+                SearchResultOrDocument::WebResult(result) => {
+                    assert_eq!(result.url, format!("https://{}.example", query));
+                }
+                SearchResultOrDocument::Document(_) => panic!("expected a web result"),
+            }
+        }
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_coalesces_identical_requests() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|_| {
+                // Keep the first request in flight while the second one starts
+                std::thread::sleep(Duration::from_millis(200));
+                json!({
+                    "success": true,
+                    "data": { "web": [{ "url": "https://example.com" }] }
+                })
+                .to_string()
+                .into()
+            })
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_config(ClientConfig {
+                coalesce_searches: true,
+                ..Default::default()
+            })
+            .unwrap();
+        let options = SearchOptions {
+            limit: Some(5),
+            ..Default::default()
+        };
+
+        let (first, second) = tokio::join!(
+            client.search("rust", options.clone()),
+            client.search("rust", options.clone())
+        );
+
+        assert_eq!(first.unwrap().data.web.unwrap().len(), 1);
+        assert_eq!(second.unwrap().data.web.unwrap().len(), 1);
+        assert!(client.in_flight_searches.0.lock().unwrap().is_empty());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_search_with_extra_fields() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .match_body(mockito::Matcher::Json(json!({
+                "query": "rust",
+                "limit": 3,
+                "freshness": "week"
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "data": {} }).to_string())
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = SearchOptions {
+            limit: Some(3),
+            extra: json!({ "freshness": "week", "limit": 10 })
+                .as_object()
+                .cloned(),
+            ..Default::default()
+        };
+        client.search("rust", options).await.unwrap();
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_search_strict_warnings() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": { "web": [{ "url": "https://example.com" }] },
+                    "warning": "Some results could not be scraped"
+                })
+                .to_string(),
+            )
+            .expect(2)
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        assert!(client.search("test query", None).await.is_ok());
+
+        let strict = client
+            .with_config(ClientConfig {
+                strict_warnings: true,
+                ..Default::default()
+            })
+            .unwrap();
+        match strict.search("test query", None).await {
+            Err(FirecrawlError::StrictWarnings(action, warnings)) => {
+                assert_eq!(action, "search");
+                assert_eq!(warnings, vec!["Some results could not be scraped"]);
+            }
+            other => panic!("expected a strict warning error, got {:?}", other),
+        }
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_search_scraped_strict_warnings() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "web": [{
+                            "url": "https://failed.example",
+                            "markdown": "",
+                            "metadata": {
+                                "sourceURL": "https://failed.example",
+                                "error": "Internal server error"
+                            }
+                        }]
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_config(ClientConfig {
+                strict_warnings: true,
+                ..Default::default()
+            })
+            .unwrap();
+        let result = client.search_scraped("test query", None).await;
+
+        match result {
+            Err(FirecrawlError::StrictWarnings(action, warnings)) => {
+                assert_eq!(action, "search");
+                assert_eq!(
+                    warnings,
+                    vec!["https://failed.example: Internal server error"]
+                );
+            }
+            other => panic!("expected a strict warning error, got {:?}", other),
+        }
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_search_coalescing_respects_scrape_timeout() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mut mocks = Vec::new();
+        for timeout in [1000, 2000] {
+            mocks.push(
+                server
+                    .mock("POST", "/v2/search")
+                    .match_body(mockito::Matcher::PartialJson(json!({
+                        "scrapeOptions": { "timeout": timeout }
+                    })))
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body_from_request(|_| {
+                        std::thread::sleep(Duration::from_millis(100));
+                        json!({ "success": true, "data": {} }).to_string().into()
+                    })
+                    .expect(1)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_config(ClientConfig {
+                coalesce_searches: true,
+                ..Default::default()
+            })
+            .unwrap();
+        let options = |scrape_timeout| SearchOptions {
+            scrape_options: Some(ScrapeOptions::default()),
+            scrape_timeout: Some(scrape_timeout),
+            ..Default::default()
+        };
+
+        let (first, second) = tokio::join!(
+            client.search("rust", options(1000)),
+            client.search("rust", options(2000))
+        );
+
+        first.unwrap();
+        second.unwrap();
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_coalescing_shares_errors() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(200));
+                "not json".into()
+            })
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_config(ClientConfig {
+                coalesce_searches: true,
+                ..Default::default()
+            })
+            .unwrap();
+
+        let (first, second) =
+            tokio::join!(client.search("rust", None), client.search("rust", None));
+
+        // A parse error cannot be cloned, so every caller gets it behind the shared Arc
+        for result in [first, second] {
+            assert!(matches!(
+                result,
+                Err(FirecrawlError::Shared(ref shared))
+                    if matches!(shared.as_ref(), FirecrawlError::ResponseParseError(_))
+            ));
+        }
+        mock.assert_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(200));
+                json!({ "success": false, "error": "Invalid API key" })
+                    .to_string()
+                    .into()
+            })
+            .expect(1)
+            .create_async()
+            .await;
+
+        let (first, second) =
+            tokio::join!(client.search("rust", None), client.search("rust", None));
+
+        // Other errors are rebuilt as the same variant for every caller
+        for result in [first, second] {
+            assert!(matches!(
+                result,
+                Err(FirecrawlError::Unauthorized(_, ref message)) if message == "Invalid API key"
+            ));
+        }
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_search_coalescing_drops_abandoned_searches() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(300));
+                json!({ "success": true, "data": {} }).to_string().into()
+            })
+            .create_async()
+            .await;
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key"))
+            .unwrap()
+            .with_config(ClientConfig {
+                coalesce_searches: true,
+                ..Default::default()
+            })
+            .unwrap();
+
+        let abandoned =
+            tokio::time::timeout(Duration::from_millis(50), client.search("rust", None)).await;
+        assert!(abandoned.is_err());
+
+        // Dropping the only caller drops the search and its entry
+        assert!(client.in_flight_searches.0.lock().unwrap().is_empty());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_search_with_warnings() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "web": [
+                            {
+                                "markdown": "# Ok",
+                                "metadata": { "sourceURL": "https://ok.example", "statusCode": 200 }
+                            },
+                            {
+                                "markdown": "",
+                                "warning": "Page appears to be empty",
+                                "metadata": {
+                                    "sourceURL": "https://failed.example",
+                                    "statusCode": 500,
+                                    "error": "Internal server error"
+                                }
+                            },
+                            { "url": "https://unscraped.example", "title": "Unscraped" }
+                        ]
+                    },
+                    "warning": "Some results could not be scraped"
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let response = client.search("test query", None).await.unwrap();
+
+        assert!(response.has_warning());
+        let failed = Some("https://failed.example".to_string());
+        assert_eq!(
+            response.warnings(),
+            vec![
+                SearchWarning {
+                    url: None,
+                    message: "Some results could not be scraped".to_string(),
+                },
+                SearchWarning {
+                    url: failed.clone(),
+                    message: "Page appears to be empty".to_string(),
+                },
+                SearchWarning {
+                    url: failed,
+                    message: "Internal server error".to_string(),
+                },
+            ]
+        );
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_search_and_scrape_outcome() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "query": "test query",
+                "limit": 3,
+                "scrapeOptions": {}
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "web": [
+                            {
+                                "markdown": "# First",
+                                "metadata": { "sourceURL": "https://first.example" }
+                            },
+                            {
+                                "url": "https://second.example",
+                                "title": "Second",
+                                "description": "Could not be scraped"
+                            },
+                            {
+                                "markdown": "# Third",
+                                "metadata": { "sourceURL": "https://third.example" }
+                            }
+                        ]
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let outcome = client
+            .search_and_scrape_outcome("test query", 3)
+            .await
+            .unwrap();
+
+        let scraped: Vec<_> = outcome
+            .documents
+            .iter()
+            .map(|doc| doc.markdown.as_deref().unwrap())
+            .collect();
+        assert_eq!(scraped, ["# First", "# Third"]);
+        assert_eq!(outcome.unscraped.len(), 1);
+        assert_eq!(outcome.unscraped[0].url, "https://second.example");
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_search_scraped_keeps_web_metadata() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "web": [
+                            {
+                                "url": "https://first.example",
+                                "title": "First result",
+                                "description": "The top hit",
+                                "markdown": "# First",
+                                "metadata": { "sourceURL": "https://first.example" }
+                            },
+                            {
+                                "markdown": "# Second",
+                                "metadata": { "sourceURL": "https://second.example" }
+                            },
+                            {
+                                "url": "https://third.example",
+                                "title": "Third result"
+                            },
+                            {
+                                "markdown": "# Unattributed",
+                                "metadata": { "statusCode": 200 }
+                            }
+                        ]
+                    },
+                    "warning": "Some results could not be scraped"
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = SearchOptions {
+            scrape_options: Some(ScrapeOptions::default()),
+            ..Default::default()
+        };
+        let response = client.search_scraped("test query", options).await.unwrap();
+
+        assert_eq!(
+            response.warning.as_deref(),
+            Some("Some results could not be scraped")
+        );
+        // The result without any URL is skipped
+        let results = response.data.web;
+        assert_eq!(results.len(), 3);
+
+        assert_eq!(results[0].web.url, "https://first.example");
+        assert_eq!(results[0].web.title.as_deref(), Some("First result"));
+        assert_eq!(results[0].web.description.as_deref(), Some("The top hit"));
+        let first = results[0].document.as_ref().unwrap();
+        assert_eq!(first.markdown.as_deref(), Some("# First"));
+
+        assert_eq!(results[1].web.url, "https://second.example");
+        assert!(results[1].document.is_some());
+
+        assert_eq!(results[2].web.title.as_deref(), Some("Third result"));
+        assert!(results[2].document.is_none());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_search_without_warnings() {
+        let response: SearchResponse = serde_json::from_value(json!({
+            "success": true,
+            "data": { "web": [{ "url": "https://example.com" }] }
+        }))
+        .unwrap();
+
+        assert!(!response.has_warning());
+        assert!(response.warnings().is_empty());
+    }
+
     #[tokio::test]
     async fn test_search_with_options() {
         let mut server = mockito::Server::new_async().await;
@@ -339,6 +1399,43 @@ mod tests {
         mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_search_with_scrape_timeout() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/search")
+            .match_body(mockito::Matcher::Json(json!({
+                "query": "test",
+                "timeout": 60000,
+                "scrapeOptions": {
+                    "formats": ["markdown"],
+                    "timeout": 15000
+                }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "success": true, "data": { "web": [] } }).to_string())
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = SearchOptions {
+            timeout: Some(60000),
+            scrape_options: Some(ScrapeOptions {
+                formats: Some(vec![Format::Markdown]),
+                timeout: Some(30000),
+                ..Default::default()
+            }),
+            scrape_timeout: Some(15000),
+            ..Default::default()
+        };
+
+        let response = client.search("test", options).await.unwrap();
+
+        assert!(response.success);
+        mock.assert();
+    }
+
     #[tokio::test]
     async fn test_search_and_scrape() {
         let mut server = mockito::Server::new_async().await;
@@ -463,4 +1560,23 @@ mod tests {
 
         mock.assert();
     }
+
+    #[test]
+    fn test_search_result_url() {
+        let web = SearchResultOrDocument::WebResult(SearchResultWeb {
+            url: "https://example.com/web".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(web.url().as_deref(), Some("https://example.com/web"));
+
+        let doc: SearchResultOrDocument = serde_json::from_value(json!({
+            "markdown": "# Scraped",
+            "metadata": { "sourceURL": "https://example.com/doc" }
+        }))
+        .unwrap();
+        assert_eq!(doc.url().as_deref(), Some("https://example.com/doc"));
+
+        let doc = SearchResultOrDocument::Document(Document::default());
+        assert_eq!(doc.url(), None);
+    }
 }
@@ -76,6 +76,47 @@ pub enum ChangeTrackingMode {
     Json,
 }
 
+/// Change tracking result for a scraped page.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeTracking {
+    /// Timestamp of the previous scrape this page was compared against.
+    pub previous_scrape_at: Option<String>,
+    /// How the page changed since the previous scrape.
+    pub change_status: Option<ChangeStatus>,
+    /// Whether the page is visible or hidden (e.g. no longer linked).
+    pub visibility: Option<PageVisibility>,
+    /// Git-style diff, present when `ChangeTrackingMode::GitDiff` was requested.
+    pub diff: Option<Value>,
+    /// Structured changes, present when `ChangeTrackingMode::Json` was requested.
+    pub json: Option<Value>,
+}
+
+/// Change status of a page compared to its previous scrape.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeStatus {
+    New,
+    Same,
+    Changed,
+    Removed,
+    /// A status this SDK version does not know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Visibility of a page tracked for changes.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PageVisibility {
+    Visible,
+    Hidden,
+    /// A visibility this SDK version does not know about.
+    #[serde(other)]
+    Unknown,
+}
+
 /// Attribute extraction selector.
 #[serde_with::skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, Clone)]
@@ -353,6 +394,10 @@ pub struct DocumentMetadata {
     pub cached_at: Option<String>,
     pub credits_used: Option<u32>,
     pub concurrency_limited: Option<bool>,
+
+    /// Any metadata keys not covered by the fields above, such as custom `<meta>` tags.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 /// Extracted attribute result.
@@ -393,12 +438,92 @@ pub struct Document {
     pub actions: Option<HashMap<String, Value>>,
     /// Warning message.
     pub warning: Option<String>,
-    /// Change tracking data.
+    /// Change tracking data, present if `Format::ChangeTracking` was requested.
+    /// See [`Document::parsed_change_tracking`] for a typed view.
     pub change_tracking: Option<Value>,
     /// Branding analysis.
     pub branding: Option<Value>,
 }
 
+impl Document {
+    /// Splits the page's links into internal and external ones.
+    ///
+    /// Links are compared against the page's `sourceURL`. Returns `None` if
+    /// links were not requested (`Format::Links`) or the source URL is unknown.
+    pub fn link_set(&self) -> Option<LinkSet> {
+        let base_url = self.metadata.as_ref()?.source_url.as_deref()?;
+        Some(LinkSet::classify(base_url, self.links.as_ref()?))
+    }
+
+    /// Parses `change_tracking` into a [`ChangeTracking`].
+    ///
+    /// Returns `None` if change tracking was not requested or the data is not
+    /// in the expected shape.
+    pub fn parsed_change_tracking(&self) -> Option<ChangeTracking> {
+        ChangeTracking::deserialize(self.change_tracking.as_ref()?).ok()
+    }
+}
+
+/// Links categorized by whether they point to the same site as a base URL.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct LinkSet {
+    /// Links on the same host as the base URL (ignoring a leading `www.`).
+    pub internal: Vec<String>,
+    /// Links to other hosts.
+    pub external: Vec<String>,
+}
+
+impl LinkSet {
+    /// Categorizes `links` relative to `base_url`.
+    ///
+    /// Relative links are resolved against `base_url` and counted as internal.
+    /// Links that are not http(s), such as `mailto:` or `javascript:`, are skipped.
+    /// The original link strings are kept, in their original order.
+    pub fn classify<I, S>(base_url: &str, links: I) -> LinkSet
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let base = reqwest::Url::parse(base_url).ok();
+        let base_host = base
+            .as_ref()
+            .and_then(|url| url.host_str())
+            .map(normalize_host);
+
+        let mut link_set = LinkSet::default();
+        for link in links {
+            let link = link.as_ref();
+            let resolved = match &base {
+                Some(base) => base.join(link),
+                None => reqwest::Url::parse(link),
+            };
+            let Ok(resolved) = resolved else {
+                continue;
+            };
+            if !matches!(resolved.scheme(), "http" | "https") {
+                continue;
+            }
+
+            let host = resolved.host_str().map(normalize_host);
+            if host.is_some() && host == base_host {
+                link_set.internal.push(link.to_string());
+            } else {
+                link_set.external.push(link.to_string());
+            }
+        }
+        link_set
+    }
+}
+
+/// Lowercases a host and strips a leading `www.` for comparison.
+pub(crate) fn normalize_host(host: &str) -> String {
+    let host = host.to_ascii_lowercase();
+    match host.strip_prefix("www.") {
+        Some(stripped) => stripped.to_string(),
+        None => host,
+    }
+}
+
 /// Job status types for crawl and batch operations.
 #[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -474,6 +599,75 @@ pub struct SearchResultNews {
     pub category: Option<String>,
 }
 
+#[cfg(feature = "chrono")]
+impl SearchResultNews {
+    /// Parses `date` into a UTC timestamp.
+    ///
+    /// Handles RFC 3339 and RFC 2822 timestamps, plain dates such as
+    /// `2024-01-05` or `Jan 5, 2024`, and relative dates such as `3 hours ago`,
+    /// which are resolved against the current time. Returns `None` if the date
+    /// is missing or in an unrecognized format.
+    ///
+    /// Only available with the `chrono` feature.
+    pub fn parsed_date(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        parse_news_date(self.date.as_deref()?, chrono::Utc::now())
+    }
+}
+
+/// Parses a news date, resolving relative dates against `now`.
+#[cfg(feature = "chrono")]
+fn parse_news_date(
+    date: &str,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<chrono::DateTime<chrono::Utc>> {
+    use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+
+    let date = date.trim();
+
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(date) {
+        return Some(parsed.with_timezone(&Utc));
+    }
+    if let Ok(parsed) = DateTime::parse_from_rfc2822(date) {
+        return Some(parsed.with_timezone(&Utc));
+    }
+
+    for format in ["%Y-%m-%d", "%b %d, %Y", "%B %d, %Y", "%d %b %Y", "%d %B %Y"] {
+        if let Ok(parsed) = NaiveDate::parse_from_str(date, format) {
+            return Some(parsed.and_hms_opt(0, 0, 0)?.and_utc());
+        }
+    }
+
+    let lower = date.to_ascii_lowercase();
+    if lower == "yesterday" {
+        return now.checked_sub_signed(TimeDelta::days(1));
+    }
+
+    let mut parts = lower.strip_suffix(" ago")?.split_whitespace();
+    // Only unsigned amounts; a negative one would resolve to a future date
+    let amount = match parts.next()? {
+        "a" | "an" => 1,
+        n if n.bytes().all(|b| b.is_ascii_digit()) => n.parse::<i64>().ok()?,
+        _ => return None,
+    };
+    let unit = parts.next()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    // Amounts come from the server, so every conversion must be checked
+    let duration = match unit.trim_end_matches('s') {
+        "second" | "sec" => TimeDelta::try_seconds(amount),
+        "minute" | "min" => TimeDelta::try_minutes(amount),
+        "hour" | "hr" => TimeDelta::try_hours(amount),
+        "day" => TimeDelta::try_days(amount),
+        "week" => TimeDelta::try_weeks(amount),
+        "month" => amount.checked_mul(30).and_then(TimeDelta::try_days),
+        "year" => amount.checked_mul(365).and_then(TimeDelta::try_days),
+        _ => return None,
+    }?;
+
+    now.checked_sub_signed(duration)
+}
+
 /// Image search result.
 #[serde_with::skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, Clone)]
@@ -508,3 +702,148 @@ pub struct CrawlErrorsResponse {
     #[serde(rename = "robotsBlocked")]
     pub robots_blocked: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_document_metadata_deserialization() {
+        let metadata: DocumentMetadata = serde_json::from_value(json!({
+            "sourceURL": "https://example.com/article",
+            "statusCode": 200,
+            "title": "An Article",
+            "description": "About things",
+            "language": "en",
+            "ogTitle": "An Article (OG)",
+            "ogImage": "https://example.com/og.png",
+            "ogLocaleAlternate": ["de_DE", "fr_FR"],
+            "publishedTime": "2024-01-01T00:00:00Z",
+            "creditsUsed": 1,
+            "twitter:card": "summary_large_image",
+            "viewport": "width=device-width"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            metadata.source_url.as_deref(),
+            Some("https://example.com/article")
+        );
+        assert_eq!(metadata.status_code, Some(200));
+        assert_eq!(metadata.title.as_deref(), Some("An Article"));
+        assert_eq!(metadata.language.as_deref(), Some("en"));
+        assert_eq!(metadata.og_title.as_deref(), Some("An Article (OG)"));
+        assert_eq!(
+            metadata.og_locale_alternate,
+            Some(vec!["de_DE".to_string(), "fr_FR".to_string()])
+        );
+        assert_eq!(metadata.credits_used, Some(1));
+
+        // Only unknown keys end up in `extra`
+        assert_eq!(metadata.extra.len(), 2);
+        assert_eq!(metadata.extra["twitter:card"], "summary_large_image");
+        assert_eq!(metadata.extra["viewport"], "width=device-width");
+
+        let value = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(value["twitter:card"], "summary_large_image");
+        assert_eq!(value["sourceURL"], "https://example.com/article");
+    }
+
+    #[test]
+    fn test_link_set_classification() {
+        let document: Document = serde_json::from_value(json!({
+            "links": [
+                "https://example.com/about",
+                "https://www.Example.com/blog?page=2",
+                "/contact",
+                "https://docs.example.com/guide",
+                "https://github.com/firecrawl/firecrawl",
+                "http://twitter.com/firecrawl",
+                "mailto:hello@example.com"
+            ],
+            "metadata": { "sourceURL": "https://example.com/" }
+        }))
+        .unwrap();
+
+        let link_set = document.link_set().unwrap();
+        assert_eq!(
+            link_set.internal,
+            vec![
+                "https://example.com/about",
+                "https://www.Example.com/blog?page=2",
+                "/contact",
+            ]
+        );
+        assert_eq!(
+            link_set.external,
+            vec![
+                "https://docs.example.com/guide",
+                "https://github.com/firecrawl/firecrawl",
+                "http://twitter.com/firecrawl",
+            ]
+        );
+
+        // Without links or a source URL there is nothing to classify
+        assert!(Document::default().link_set().is_none());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_news_parsed_date() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let now = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        let expected = |y, m, d, h, min| Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap();
+
+        assert_eq!(
+            parse_news_date("2024-01-05T08:30:00+02:00", now),
+            Some(expected(2024, 1, 5, 6, 30))
+        );
+        assert_eq!(
+            parse_news_date("Fri, 05 Jan 2024 08:30:00 GMT", now),
+            Some(expected(2024, 1, 5, 8, 30))
+        );
+        assert_eq!(
+            parse_news_date("2024-01-05", now),
+            Some(expected(2024, 1, 5, 0, 0))
+        );
+        assert_eq!(
+            parse_news_date("Jan 5, 2024", now),
+            Some(expected(2024, 1, 5, 0, 0))
+        );
+        assert_eq!(
+            parse_news_date("3 hours ago", now),
+            Some(now - Duration::hours(3))
+        );
+        assert_eq!(
+            parse_news_date("1 day ago", now),
+            Some(now - Duration::days(1))
+        );
+        assert_eq!(
+            parse_news_date("an hour ago", now),
+            Some(now - Duration::hours(1))
+        );
+
+        assert_eq!(parse_news_date("sometime last week", now), None);
+        assert_eq!(parse_news_date("3 fortnights ago", now), None);
+        assert_eq!(parse_news_date("-3 hours ago", now), None);
+        assert_eq!(parse_news_date("+3 hours ago", now), None);
+        assert_eq!(parse_news_date("", now), None);
+
+        // Out-of-range amounts are rejected instead of panicking
+        assert_eq!(parse_news_date("99999999999999 days ago", now), None);
+        assert_eq!(parse_news_date("9223372036854775807 years ago", now), None);
+        assert_eq!(
+            parse_news_date("9223372036854775807 seconds ago", now),
+            None
+        );
+
+        let news = SearchResultNews {
+            date: Some("not a date".to_string()),
+            ..Default::default()
+        };
+        assert!(news.parsed_date().is_none());
+        assert!(SearchResultNews::default().parsed_date().is_none());
+    }
+}
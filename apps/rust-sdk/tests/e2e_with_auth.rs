@@ -143,8 +143,8 @@ async fn test_llm_extraction() {
         .scrape_url("https://mendable.ai", options)
         .await
         .unwrap();
-    assert!(result.extract.is_some());
-    let llm_extraction = &result.extract.unwrap();
+    assert!(result.json.is_some());
+    let llm_extraction = &result.json.unwrap();
     assert!(llm_extraction
         .as_object()
         .unwrap()